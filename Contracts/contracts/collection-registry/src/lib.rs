@@ -0,0 +1,246 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, token, Address, BytesN, Env, IntoVal,
+    String, Symbol, Vec,
+};
+
+// Contract Errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RegistryError {
+    AlreadyRegistered = 1,
+    NotRegistered = 2,
+    UnknownCollection = 3,
+    AlreadyInitialized = 4,
+    NotInitialized = 5,
+    Unauthorized = 6,
+}
+
+// Storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Factory, // Token factory whose deployments may register
+    CollectionCount,
+    Collection(Address),         // Registry entry per collection contract
+    CollectionAt(u32),           // Registry index -> collection contract
+    CreatorCollections(Address), // Collections registered by a creator
+}
+
+// Registry entry for a collection contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionInfo {
+    pub collection: Address,
+    pub name: String,
+    pub symbol: String,
+    pub creator: Address,       // Deployer recorded by the token factory
+    pub standards: Vec<Symbol>, // e.g. "sep41"
+    pub registered_at: u32,     // Ledger sequence of registration
+}
+
+// Deployment record as stored by the token factory
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deployment {
+    pub token: Address,
+    pub deployer: Address,
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+    pub deployed_at: u32,
+}
+
+/// Discovery registry of tokens deployed through the Stellara token factory, so
+/// wallets and marketplaces can list collections without an off-chain list.
+#[contract]
+pub struct CollectionRegistryContract;
+
+#[contractimpl]
+impl CollectionRegistryContract {
+    // ========== INITIALIZATION ==========
+
+    /// Initialize the registry with its admin and the token factory it trusts
+    pub fn initialize(env: Env, admin: Address, factory: Address) -> Result<(), RegistryError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(RegistryError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Factory, &factory);
+
+        Ok(())
+    }
+
+    // ========== ADMIN FUNCTIONS ==========
+
+    /// Trust a new token factory (already registered collections are kept)
+    pub fn set_factory(env: Env, admin: Address, factory: Address) -> Result<(), RegistryError> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(RegistryError::NotInitialized)?;
+        if admin != stored_admin {
+            return Err(RegistryError::Unauthorized);
+        }
+
+        env.storage().instance().set(&DataKey::Factory, &factory);
+
+        env.events()
+            .publish((Symbol::new(&env, "factory_updated"),), factory);
+
+        Ok(())
+    }
+
+    // ========== REGISTRATION ==========
+
+    /// Register a collection deployed by the trusted factory. Its deployer must
+    /// authorize; name and symbol are read from the collection itself.
+    pub fn register(
+        env: Env,
+        collection: Address,
+        standards: Vec<Symbol>,
+    ) -> Result<u32, RegistryError> {
+        let creator = Self::factory_deployment(&env, &collection)?.deployer;
+        creator.require_auth();
+
+        let key = DataKey::Collection(collection.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(RegistryError::AlreadyRegistered);
+        }
+
+        let metadata = token::Client::new(&env, &collection);
+        let info = CollectionInfo {
+            collection: collection.clone(),
+            name: metadata.name(),
+            symbol: metadata.symbol(),
+            creator: creator.clone(),
+            standards,
+            registered_at: env.ledger().sequence(),
+        };
+
+        let index = Self::collection_count(env.clone());
+        env.storage().persistent().set(&key, &info);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CollectionAt(index), &collection);
+        env.storage()
+            .instance()
+            .set(&DataKey::CollectionCount, &(index + 1));
+
+        let creator_key = DataKey::CreatorCollections(creator.clone());
+        let mut created: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&creator_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        created.push_back(collection.clone());
+        env.storage().persistent().set(&creator_key, &created);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "collection_registered"), creator),
+            (collection, index),
+        );
+
+        Ok(index)
+    }
+
+    /// Update the standards a registered collection advertises (e.g. after an
+    /// upgrade). Its creator must authorize.
+    pub fn update_standards(
+        env: Env,
+        collection: Address,
+        standards: Vec<Symbol>,
+    ) -> Result<(), RegistryError> {
+        let key = DataKey::Collection(collection.clone());
+        let mut info: CollectionInfo = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(RegistryError::NotRegistered)?;
+        info.creator.require_auth();
+
+        info.standards = standards;
+        env.storage().persistent().set(&key, &info);
+
+        env.events()
+            .publish((Symbol::new(&env, "standards_updated"),), collection);
+
+        Ok(())
+    }
+
+    // ========== QUERIES ==========
+
+    /// Get the registry entry for a collection
+    pub fn get_collection(env: Env, collection: Address) -> Option<CollectionInfo> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Collection(collection))
+    }
+
+    /// Get the number of registered collections
+    pub fn collection_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CollectionCount)
+            .unwrap_or(0)
+    }
+
+    /// Get a page of collections in registration order
+    pub fn collections(env: Env, start: u32, limit: u32) -> Vec<CollectionInfo> {
+        let count = Self::collection_count(env.clone());
+        let end = start.saturating_add(limit).min(count);
+
+        let mut page = Vec::new(&env);
+        for index in start..end {
+            let collection: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::CollectionAt(index))
+                .unwrap();
+            let info: CollectionInfo = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Collection(collection))
+                .unwrap();
+            page.push_back(info);
+        }
+
+        page
+    }
+
+    /// Get the collections registered by a creator
+    pub fn collections_by_creator(env: Env, creator: Address) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CreatorCollections(creator))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn factory_deployment(env: &Env, collection: &Address) -> Result<Deployment, RegistryError> {
+        let factory: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Factory)
+            .ok_or(RegistryError::NotInitialized)?;
+
+        let deployment: Option<Deployment> = env.invoke_contract(
+            &factory,
+            &Symbol::new(env, "get_deployment"),
+            (collection.clone(),).into_val(env),
+        );
+        deployment.ok_or(RegistryError::UnknownCollection)
+    }
+}
+
+#[cfg(test)]
+mod test;
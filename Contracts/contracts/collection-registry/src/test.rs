@@ -0,0 +1,202 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    vec, Env,
+};
+use token_factory::{TokenFactoryContract, TokenFactoryContractClient};
+
+mod token_wasm {
+    soroban_sdk::contractimport!(file = "../token-factory/testdata/token.wasm");
+}
+
+fn setup_contract(
+    env: &Env,
+) -> (
+    CollectionRegistryContractClient<'_>,
+    TokenFactoryContractClient<'_>,
+) {
+    let factory_id = env.register_contract(None, TokenFactoryContract);
+    let factory = TokenFactoryContractClient::new(env, &factory_id);
+    let contract_id = env.register_contract(None, CollectionRegistryContract);
+    let client = CollectionRegistryContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    // Deploying real token wasm several times exceeds the default test budget
+    env.budget().reset_unlimited();
+    env.mock_all_auths();
+    let wasm_hash = env.deployer().upload_contract_wasm(token_wasm::WASM);
+    factory.initialize(&admin, &wasm_hash);
+    client.initialize(&admin, &factory_id);
+
+    (client, factory)
+}
+
+/// Deploy a token through the factory, signed only by `creator`
+fn create_collection(
+    env: &Env,
+    factory: &TokenFactoryContractClient,
+    creator: &Address,
+    name: &str,
+) -> Address {
+    let salt = BytesN::from_array(env, &[factory.deployment_count() as u8; 32]);
+    let token = factory.deployed_address(creator, &salt);
+    let name = String::from_str(env, name);
+    let symbol = String::from_str(env, "STL");
+
+    factory
+        .mock_auths(&[MockAuth {
+            address: creator,
+            invoke: &MockAuthInvoke {
+                contract: &factory.address,
+                fn_name: "deploy",
+                args: (
+                    creator.clone(),
+                    salt.clone(),
+                    creator.clone(),
+                    7_u32,
+                    name.clone(),
+                    symbol.clone(),
+                )
+                    .into_val(env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &token,
+                    fn_name: "initialize",
+                    args: (creator.clone(), 7_u32, name.clone(), symbol.clone()).into_val(env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .deploy(creator, &salt, creator, &7, &name, &symbol)
+}
+
+/// Register `collection` with only its creator's signature
+fn register(
+    env: &Env,
+    client: &CollectionRegistryContractClient,
+    creator: &Address,
+    collection: &Address,
+) -> u32 {
+    let standards = vec![env, symbol_short!("sep41")];
+    client
+        .mock_auths(&[MockAuth {
+            address: creator,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "register",
+                args: (collection.clone(), standards.clone()).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .register(collection, &standards)
+}
+
+#[test]
+fn test_register_collection() {
+    let env = Env::default();
+    let (client, factory) = setup_contract(&env);
+    let creator = Address::generate(&env);
+    let collection = create_collection(&env, &factory, &creator, "Genesis");
+
+    assert_eq!(register(&env, &client, &creator, &collection), 0);
+
+    // Name, symbol and creator come from the collection and factory, not the caller
+    let info = client.get_collection(&collection).unwrap();
+    assert_eq!(info.creator, creator);
+    assert_eq!(info.name, String::from_str(&env, "Genesis"));
+    assert_eq!(info.symbol, String::from_str(&env, "STL"));
+    assert_eq!(info.standards, vec![&env, symbol_short!("sep41")]);
+    assert_eq!(client.collection_count(), 1);
+}
+
+#[test]
+fn test_cannot_register_twice() {
+    let env = Env::default();
+    let (client, factory) = setup_contract(&env);
+    let creator = Address::generate(&env);
+    let collection = create_collection(&env, &factory, &creator, "Genesis");
+
+    register(&env, &client, &creator, &collection);
+
+    env.mock_all_auths();
+    let result = client.try_register(&collection, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(RegistryError::AlreadyRegistered)));
+}
+
+#[test]
+fn test_collection_outside_factory_rejected() {
+    let env = Env::default();
+    let (client, _factory) = setup_contract(&env);
+
+    // Same token code, but deployed directly rather than through the factory
+    let spoof = env.register_contract_wasm(None, token_wasm::WASM);
+    token_wasm::Client::new(&env, &spoof).initialize(
+        &Address::generate(&env),
+        &7,
+        &String::from_str(&env, "Genesis"),
+        &String::from_str(&env, "STL"),
+    );
+
+    let result = client.try_register(&spoof, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(RegistryError::UnknownCollection)));
+    assert_eq!(client.collection_count(), 0);
+}
+
+#[test]
+fn test_collections_pagination() {
+    let env = Env::default();
+    let (client, factory) = setup_contract(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let first = create_collection(&env, &factory, &alice, "One");
+    let second = create_collection(&env, &factory, &bob, "Two");
+    let third = create_collection(&env, &factory, &alice, "Three");
+    register(&env, &client, &alice, &first);
+    register(&env, &client, &bob, &second);
+    register(&env, &client, &alice, &third);
+
+    let page = client.collections(&1, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().collection, second);
+    assert_eq!(page.get(1).unwrap().collection, third);
+
+    assert_eq!(client.collections(&3, &10).len(), 0);
+    assert_eq!(
+        client.collections_by_creator(&alice),
+        vec![&env, first, third]
+    );
+}
+
+#[test]
+fn test_update_standards() {
+    let env = Env::default();
+    let (client, factory) = setup_contract(&env);
+    let creator = Address::generate(&env);
+    let collection = create_collection(&env, &factory, &creator, "Genesis");
+
+    register(&env, &client, &creator, &collection);
+
+    let standards = vec![&env, symbol_short!("sep41"), symbol_short!("nft")];
+    client
+        .mock_auths(&[MockAuth {
+            address: &creator,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "update_standards",
+                args: (collection.clone(), standards.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .update_standards(&collection, &standards);
+
+    let info = client.get_collection(&collection).unwrap();
+    assert_eq!(info.standards.len(), 2);
+
+    env.mock_all_auths();
+    let unregistered = create_collection(&env, &factory, &creator, "Unlisted");
+    let result = client.try_update_standards(&unregistered, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(RegistryError::NotRegistered)));
+}
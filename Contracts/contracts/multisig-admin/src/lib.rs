@@ -0,0 +1,337 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Val, Vec,
+};
+
+// Contract Errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MultisigError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotOwner = 3,
+    InvalidThreshold = 4,
+    TransactionNotFound = 5,
+    AlreadyConfirmed = 6,
+    NotConfirmed = 7,
+    AlreadyExecuted = 8,
+    InsufficientConfirmations = 9,
+    AlreadyOwner = 10,
+    TransactionCancelled = 11,
+    NotProposer = 12,
+}
+
+// Storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Owners,
+    Threshold,
+    TransactionCount,
+    Transaction(u32),
+}
+
+/// What a multisig transaction does once it has enough confirmations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Invoke `function` on `target` with `args`, authorized as this contract
+    Call(Address, Symbol, Vec<Val>),
+    AddOwner(Address),
+    RemoveOwner(Address),
+    SetThreshold(u32),
+}
+
+// Submitted transaction awaiting confirmations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transaction {
+    pub id: u32,
+    pub action: Action,
+    pub proposer: Address,
+    pub confirmations: Vec<Address>,
+    pub executed: bool,
+    pub cancelled: bool,
+    pub created_at: u32, // Ledger sequence of submission
+}
+
+/// Minimal m-of-n multisig meant to be set as a token contract's admin.
+///
+/// Owners submit and confirm actions; once `threshold` current owners have
+/// confirmed, anyone may execute. Calls into the token are authorized as this
+/// contract's address, so the token only ever sees a single admin.
+#[contract]
+pub struct MultisigAdminContract;
+
+#[contractimpl]
+impl MultisigAdminContract {
+    // ========== INITIALIZATION ==========
+
+    /// Initialize the multisig with its owner set and confirmation threshold.
+    /// Every initial owner must authorize being enrolled.
+    pub fn initialize(env: Env, owners: Vec<Address>, threshold: u32) -> Result<(), MultisigError> {
+        if env.storage().instance().has(&DataKey::Owners) {
+            return Err(MultisigError::AlreadyInitialized);
+        }
+
+        Self::validate_threshold(owners.len(), threshold)?;
+
+        let mut unique = Vec::new(&env);
+        for owner in owners.iter() {
+            if unique.contains(&owner) {
+                return Err(MultisigError::AlreadyOwner);
+            }
+            unique.push_back(owner);
+        }
+
+        for owner in unique.iter() {
+            owner.require_auth();
+        }
+
+        env.storage().instance().set(&DataKey::Owners, &unique);
+        env.storage()
+            .instance()
+            .set(&DataKey::Threshold, &threshold);
+        env.storage()
+            .instance()
+            .set(&DataKey::TransactionCount, &0u32);
+
+        Ok(())
+    }
+
+    // ========== OWNER FUNCTIONS ==========
+
+    /// Submit an action; the proposer's confirmation is recorded immediately
+    pub fn submit(env: Env, owner: Address, action: Action) -> Result<u32, MultisigError> {
+        Self::require_owner(&env, &owner)?;
+
+        let id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TransactionCount)
+            .unwrap_or(0);
+
+        let transaction = Transaction {
+            id,
+            action,
+            proposer: owner.clone(),
+            confirmations: Vec::from_array(&env, [owner.clone()]),
+            executed: false,
+            cancelled: false,
+            created_at: env.ledger().sequence(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(id), &transaction);
+        env.storage()
+            .instance()
+            .set(&DataKey::TransactionCount, &(id + 1));
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "tx_submitted"), owner), id);
+
+        Ok(id)
+    }
+
+    /// Confirm a pending transaction
+    pub fn confirm(env: Env, owner: Address, tx_id: u32) -> Result<(), MultisigError> {
+        Self::require_owner(&env, &owner)?;
+
+        let mut transaction = Self::get_pending(&env, tx_id)?;
+        if transaction.confirmations.contains(&owner) {
+            return Err(MultisigError::AlreadyConfirmed);
+        }
+
+        transaction.confirmations.push_back(owner.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(tx_id), &transaction);
+
+        env.events()
+            .publish((Symbol::new(&env, "tx_confirmed"), owner), tx_id);
+
+        Ok(())
+    }
+
+    /// Withdraw an earlier confirmation from a pending transaction
+    pub fn revoke(env: Env, owner: Address, tx_id: u32) -> Result<(), MultisigError> {
+        Self::require_owner(&env, &owner)?;
+
+        let mut transaction = Self::get_pending(&env, tx_id)?;
+        let index = transaction
+            .confirmations
+            .first_index_of(&owner)
+            .ok_or(MultisigError::NotConfirmed)?;
+
+        transaction.confirmations.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(tx_id), &transaction);
+
+        env.events()
+            .publish((Symbol::new(&env, "tx_revoked"), owner), tx_id);
+
+        Ok(())
+    }
+
+    /// Cancel a pending transaction. Only its proposer may cancel, unless the
+    /// proposer has since been removed, in which case any owner may.
+    pub fn cancel(env: Env, owner: Address, tx_id: u32) -> Result<(), MultisigError> {
+        Self::require_owner(&env, &owner)?;
+
+        let mut transaction = Self::get_pending(&env, tx_id)?;
+        let owners = Self::get_owners(env.clone());
+        if transaction.proposer != owner && owners.contains(&transaction.proposer) {
+            return Err(MultisigError::NotProposer);
+        }
+
+        transaction.cancelled = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(tx_id), &transaction);
+
+        env.events()
+            .publish((Symbol::new(&env, "tx_cancelled"), owner), tx_id);
+
+        Ok(())
+    }
+
+    /// Execute a transaction that has reached the threshold. Anyone may call.
+    pub fn execute(env: Env, tx_id: u32) -> Result<Val, MultisigError> {
+        let mut transaction = Self::get_pending(&env, tx_id)?;
+
+        // Only confirmations from current owners count after a rotation
+        let owners = Self::get_owners(env.clone());
+        let confirmed = transaction
+            .confirmations
+            .iter()
+            .filter(|c| owners.contains(c))
+            .count() as u32;
+
+        if confirmed < Self::get_threshold(env.clone()) {
+            return Err(MultisigError::InsufficientConfirmations);
+        }
+
+        // Mark executed before any external call
+        transaction.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(tx_id), &transaction);
+
+        let result = match transaction.action {
+            Action::Call(target, function, args) => env.invoke_contract(&target, &function, args),
+            Action::AddOwner(owner) => {
+                let mut owners = owners;
+                if owners.contains(&owner) {
+                    return Err(MultisigError::AlreadyOwner);
+                }
+                owners.push_back(owner);
+                env.storage().instance().set(&DataKey::Owners, &owners);
+                Val::VOID.into()
+            }
+            Action::RemoveOwner(owner) => {
+                let mut owners = owners;
+                let index = owners
+                    .first_index_of(&owner)
+                    .ok_or(MultisigError::NotOwner)?;
+                owners.remove(index);
+                Self::validate_threshold(owners.len(), Self::get_threshold(env.clone()))?;
+                env.storage().instance().set(&DataKey::Owners, &owners);
+                Val::VOID.into()
+            }
+            Action::SetThreshold(threshold) => {
+                Self::validate_threshold(owners.len(), threshold)?;
+                env.storage()
+                    .instance()
+                    .set(&DataKey::Threshold, &threshold);
+                Val::VOID.into()
+            }
+        };
+
+        env.events()
+            .publish((Symbol::new(&env, "tx_executed"),), tx_id);
+
+        Ok(result)
+    }
+
+    // ========== QUERIES ==========
+
+    /// Get the current owner set
+    pub fn get_owners(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Owners)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the number of confirmations required to execute
+    pub fn get_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .unwrap_or(0)
+    }
+
+    /// Get a submitted transaction
+    pub fn get_transaction(env: Env, tx_id: u32) -> Option<Transaction> {
+        env.storage().persistent().get(&DataKey::Transaction(tx_id))
+    }
+
+    /// Get the number of submitted transactions
+    pub fn transaction_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TransactionCount)
+            .unwrap_or(0)
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn require_owner(env: &Env, owner: &Address) -> Result<(), MultisigError> {
+        owner.require_auth();
+
+        let owners: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Owners)
+            .ok_or(MultisigError::NotInitialized)?;
+
+        if !owners.contains(owner) {
+            return Err(MultisigError::NotOwner);
+        }
+
+        Ok(())
+    }
+
+    fn get_pending(env: &Env, tx_id: u32) -> Result<Transaction, MultisigError> {
+        let transaction: Transaction = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Transaction(tx_id))
+            .ok_or(MultisigError::TransactionNotFound)?;
+
+        if transaction.executed {
+            return Err(MultisigError::AlreadyExecuted);
+        }
+        if transaction.cancelled {
+            return Err(MultisigError::TransactionCancelled);
+        }
+
+        Ok(transaction)
+    }
+
+    fn validate_threshold(owner_count: u32, threshold: u32) -> Result<(), MultisigError> {
+        if threshold == 0 || threshold > owner_count {
+            return Err(MultisigError::InvalidThreshold);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
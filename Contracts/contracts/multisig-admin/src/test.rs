@@ -0,0 +1,217 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use ::token::{Role, TokenContract, TokenContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Env, IntoVal, String};
+
+/// Deploy the Stellara token with the multisig as its admin
+fn create_token<'a>(env: &Env, admin: &Address) -> TokenContractClient<'a> {
+    let token = TokenContractClient::new(env, &env.register_contract(None, TokenContract));
+    token.initialize(
+        admin,
+        &7,
+        &String::from_str(env, "Stellara"),
+        &String::from_str(env, "STLR"),
+    );
+    token
+}
+
+fn setup_contract(env: &Env) -> (MultisigAdminContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, MultisigAdminContract);
+    let client = MultisigAdminContractClient::new(env, &contract_id);
+
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    let carol = Address::generate(env);
+
+    env.mock_all_auths();
+    client.initialize(&vec![env, alice.clone(), bob.clone(), carol.clone()], &2);
+
+    (client, alice, bob, carol)
+}
+
+#[test]
+fn test_multisig_initialization() {
+    let env = Env::default();
+    let (client, alice, bob, carol) = setup_contract(&env);
+
+    // Every initial owner signed the enrollment
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(
+        signers,
+        std::vec![alice.clone(), bob.clone(), carol.clone()]
+    );
+
+    assert_eq!(client.get_owners(), vec![&env, alice.clone(), bob, carol]);
+    assert_eq!(client.get_threshold(), 2);
+
+    let result = client.try_initialize(&vec![&env, alice], &1);
+    assert_eq!(result, Err(Ok(MultisigError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_invalid_threshold_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigAdminContract);
+    let client = MultisigAdminContractClient::new(&env, &contract_id);
+    let alice = Address::generate(&env);
+
+    let result = client.try_initialize(&vec![&env, alice.clone()], &2);
+    assert_eq!(result, Err(Ok(MultisigError::InvalidThreshold)));
+
+    let result = client.try_initialize(&vec![&env, alice], &0);
+    assert_eq!(result, Err(Ok(MultisigError::InvalidThreshold)));
+}
+
+#[test]
+fn test_call_executes_as_admin_after_threshold() {
+    let env = Env::default();
+    let (client, alice, bob, _carol) = setup_contract(&env);
+
+    let token = create_token(&env, &client.address);
+    let minter = Address::generate(&env);
+
+    let action = Action::Call(
+        token.address.clone(),
+        Symbol::new(&env, "grant_role"),
+        vec![&env, Role::Minter.into_val(&env), minter.into_val(&env)],
+    );
+    let tx_id = client.submit(&alice, &action);
+
+    // One confirmation is not enough
+    let result = client.try_execute(&tx_id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(MultisigError::InsufficientConfirmations))
+    );
+
+    client.confirm(&bob, &tx_id);
+    client.execute(&tx_id);
+
+    assert!(token.has_role(&Role::Minter, &minter));
+    assert!(client.get_transaction(&tx_id).unwrap().executed);
+
+    // Cannot replay
+    let result = client.try_execute(&tx_id);
+    assert_eq!(result.err(), Some(Ok(MultisigError::AlreadyExecuted)));
+
+    // As the token admin the multisig can also mint directly
+    let holder = Address::generate(&env);
+    let action = Action::Call(
+        token.address.clone(),
+        Symbol::new(&env, "mint"),
+        vec![
+            &env,
+            client.address.into_val(&env),
+            holder.into_val(&env),
+            250_i128.into_val(&env),
+        ],
+    );
+    let tx_id = client.submit(&bob, &action);
+    client.confirm(&alice, &tx_id);
+    client.execute(&tx_id);
+    assert_eq!(token.balance(&holder), 250);
+}
+
+#[test]
+fn test_confirm_and_revoke_rules() {
+    let env = Env::default();
+    let (client, alice, bob, _carol) = setup_contract(&env);
+    let outsider = Address::generate(&env);
+
+    let tx_id = client.submit(&alice, &Action::SetThreshold(3));
+
+    let result = client.try_confirm(&alice, &tx_id);
+    assert_eq!(result, Err(Ok(MultisigError::AlreadyConfirmed)));
+
+    let result = client.try_confirm(&outsider, &tx_id);
+    assert_eq!(result, Err(Ok(MultisigError::NotOwner)));
+
+    let result = client.try_revoke(&bob, &tx_id);
+    assert_eq!(result, Err(Ok(MultisigError::NotConfirmed)));
+
+    client.confirm(&bob, &tx_id);
+    client.revoke(&bob, &tx_id);
+
+    let result = client.try_execute(&tx_id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(MultisigError::InsufficientConfirmations))
+    );
+}
+
+#[test]
+fn test_owner_rotation() {
+    let env = Env::default();
+    let (client, alice, bob, carol) = setup_contract(&env);
+    let dave = Address::generate(&env);
+
+    let tx_id = client.submit(&alice, &Action::AddOwner(dave.clone()));
+    client.confirm(&bob, &tx_id);
+    client.execute(&tx_id);
+
+    let tx_id = client.submit(&dave, &Action::RemoveOwner(carol.clone()));
+    client.confirm(&alice, &tx_id);
+    client.execute(&tx_id);
+
+    assert_eq!(client.get_owners(), vec![&env, alice, bob, dave]);
+    let result = client.try_submit(&carol, &Action::SetThreshold(1));
+    assert_eq!(result, Err(Ok(MultisigError::NotOwner)));
+}
+
+#[test]
+fn test_removed_owner_confirmation_no_longer_counts() {
+    let env = Env::default();
+    let (client, alice, bob, carol) = setup_contract(&env);
+
+    // Carol proposes; before Bob confirms, Carol is removed
+    let pending = client.submit(&carol, &Action::SetThreshold(1));
+
+    let removal = client.submit(&alice, &Action::RemoveOwner(carol));
+    client.confirm(&bob, &removal);
+    client.execute(&removal);
+
+    let result = client.try_execute(&pending);
+    assert_eq!(
+        result.err(),
+        Some(Ok(MultisigError::InsufficientConfirmations))
+    );
+}
+
+#[test]
+fn test_proposer_cancels_pending_transaction() {
+    let env = Env::default();
+    let (client, alice, bob, _carol) = setup_contract(&env);
+
+    let tx_id = client.submit(&alice, &Action::SetThreshold(3));
+
+    let result = client.try_cancel(&bob, &tx_id);
+    assert_eq!(result, Err(Ok(MultisigError::NotProposer)));
+
+    client.cancel(&alice, &tx_id);
+    assert!(client.get_transaction(&tx_id).unwrap().cancelled);
+
+    let result = client.try_confirm(&bob, &tx_id);
+    assert_eq!(result, Err(Ok(MultisigError::TransactionCancelled)));
+    let result = client.try_execute(&tx_id);
+    assert_eq!(result.err(), Some(Ok(MultisigError::TransactionCancelled)));
+}
+
+#[test]
+fn test_removed_proposers_transaction_can_be_cancelled_by_owner() {
+    let env = Env::default();
+    let (client, alice, bob, carol) = setup_contract(&env);
+
+    let pending = client.submit(&carol, &Action::SetThreshold(1));
+
+    let removal = client.submit(&alice, &Action::RemoveOwner(carol));
+    client.confirm(&bob, &removal);
+    client.execute(&removal);
+
+    client.cancel(&bob, &pending);
+    assert!(client.get_transaction(&pending).unwrap().cancelled);
+}
@@ -0,0 +1,313 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Val, Vec,
+};
+
+// Contract Errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TimelockError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    DelayTooShort = 4,
+    OperationNotFound = 5,
+    OperationNotPending = 6,
+    TimelockNotExpired = 7,
+    NoProposers = 8,
+    NoExecutors = 9,
+    DelayOverflow = 10,
+}
+
+// Storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    MinDelay,
+    OperationCount,
+    Operation(u32),
+    Role(TimelockRole, Address),
+}
+
+/// Timelock role
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TimelockRole {
+    Proposer = 0, // Can schedule and cancel operations
+    Executor = 1, // Can execute operations once their delay has passed
+}
+
+/// Status of a scheduled operation
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OperationStatus {
+    Pending = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+/// What an operation does when executed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Invoke `function` on `target` with `args`, authorized as this contract
+    Call(Address, Symbol, Vec<Val>),
+    /// Change the minimum delay; itself subject to the current delay
+    SetMinDelay(u64),
+    GrantRole(TimelockRole, Address),
+    RevokeRole(TimelockRole, Address),
+}
+
+// Operation queued behind the timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    pub id: u32,
+    pub action: Action,
+    pub proposer: Address,
+    pub scheduled_at: u64,
+    pub ready_at: u64, // Timelock: when it can be executed
+    pub status: OperationStatus,
+}
+
+/// Timelock controller meant to be set as a token contract's admin.
+///
+/// Proposers queue admin calls, which executors can run only after the delay
+/// has elapsed, giving holders time to react to pending configuration changes.
+/// Role and delay changes are themselves operations, so they go through the
+/// same delay as everything else.
+#[contract]
+pub struct TimelockController;
+
+#[contractimpl]
+impl TimelockController {
+    // ========== INITIALIZATION ==========
+
+    /// Initialize with the minimum delay (in seconds) and the initial role holders.
+    /// Every initial proposer and executor must authorize being enrolled.
+    pub fn initialize(
+        env: Env,
+        proposers: Vec<Address>,
+        executors: Vec<Address>,
+        min_delay: u64,
+    ) -> Result<(), TimelockError> {
+        if env.storage().instance().has(&DataKey::MinDelay) {
+            return Err(TimelockError::AlreadyInitialized);
+        }
+        if proposers.is_empty() {
+            return Err(TimelockError::NoProposers);
+        }
+        if executors.is_empty() {
+            return Err(TimelockError::NoExecutors);
+        }
+
+        // An account may hold both roles but can only authorize once per call
+        let mut holders: Vec<Address> = Vec::new(&env);
+        for account in proposers.iter().chain(executors.iter()) {
+            if !holders.contains(&account) {
+                account.require_auth();
+                holders.push_back(account);
+            }
+        }
+
+        env.storage().instance().set(&DataKey::MinDelay, &min_delay);
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationCount, &0u32);
+
+        for proposer in proposers.iter() {
+            Self::set_role(&env, TimelockRole::Proposer, proposer, true);
+        }
+        for executor in executors.iter() {
+            Self::set_role(&env, TimelockRole::Executor, executor, true);
+        }
+
+        Ok(())
+    }
+
+    // ========== PROPOSER FUNCTIONS ==========
+
+    /// Queue an action to become executable after `delay` seconds
+    pub fn schedule(
+        env: Env,
+        proposer: Address,
+        action: Action,
+        delay: u64,
+    ) -> Result<u32, TimelockError> {
+        Self::require_role(&env, &proposer, TimelockRole::Proposer)?;
+
+        if delay < Self::get_min_delay(env.clone()) {
+            return Err(TimelockError::DelayTooShort);
+        }
+
+        let id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::OperationCount)
+            .unwrap_or(0);
+
+        let now = env.ledger().timestamp();
+        let ready_at = now.checked_add(delay).ok_or(TimelockError::DelayOverflow)?;
+        let operation = Operation {
+            id,
+            action,
+            proposer: proposer.clone(),
+            scheduled_at: now,
+            ready_at,
+            status: OperationStatus::Pending,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(id), &operation);
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationCount, &(id + 1));
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "op_scheduled"), proposer),
+            (id, operation.ready_at),
+        );
+
+        Ok(id)
+    }
+
+    /// Cancel a pending operation
+    pub fn cancel(env: Env, proposer: Address, op_id: u32) -> Result<(), TimelockError> {
+        Self::require_role(&env, &proposer, TimelockRole::Proposer)?;
+
+        let mut operation = Self::get_pending(&env, op_id)?;
+        operation.status = OperationStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(op_id), &operation);
+
+        env.events()
+            .publish((Symbol::new(&env, "op_cancelled"), proposer), op_id);
+
+        Ok(())
+    }
+
+    // ========== EXECUTOR FUNCTIONS ==========
+
+    /// Execute an operation whose delay has elapsed
+    pub fn execute(env: Env, executor: Address, op_id: u32) -> Result<Val, TimelockError> {
+        Self::require_role(&env, &executor, TimelockRole::Executor)?;
+
+        let mut operation = Self::get_pending(&env, op_id)?;
+        if env.ledger().timestamp() < operation.ready_at {
+            return Err(TimelockError::TimelockNotExpired);
+        }
+
+        // Mark executed before any external call
+        operation.status = OperationStatus::Executed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(op_id), &operation);
+
+        let result = match operation.action {
+            Action::Call(target, function, args) => env.invoke_contract(&target, &function, args),
+            Action::SetMinDelay(min_delay) => {
+                env.storage().instance().set(&DataKey::MinDelay, &min_delay);
+                Val::VOID.into()
+            }
+            Action::GrantRole(role, account) => {
+                Self::set_role(&env, role, account, true);
+                Val::VOID.into()
+            }
+            Action::RevokeRole(role, account) => {
+                Self::set_role(&env, role, account, false);
+                Val::VOID.into()
+            }
+        };
+
+        env.events()
+            .publish((Symbol::new(&env, "op_executed"), executor), op_id);
+
+        Ok(result)
+    }
+
+    // ========== QUERIES ==========
+
+    /// Get the minimum delay (in seconds) new operations must wait
+    pub fn get_min_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinDelay)
+            .unwrap_or(0)
+    }
+
+    /// Check whether an address holds a role
+    pub fn has_role(env: Env, role: TimelockRole, account: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Role(role, account))
+            .unwrap_or(false)
+    }
+
+    /// Get a scheduled operation
+    pub fn get_operation(env: Env, op_id: u32) -> Option<Operation> {
+        env.storage().persistent().get(&DataKey::Operation(op_id))
+    }
+
+    /// Check whether a pending operation can be executed now
+    pub fn is_ready(env: Env, op_id: u32) -> bool {
+        match Self::get_operation(env.clone(), op_id) {
+            Some(operation) => {
+                operation.status == OperationStatus::Pending
+                    && env.ledger().timestamp() >= operation.ready_at
+            }
+            None => false,
+        }
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn require_role(env: &Env, account: &Address, role: TimelockRole) -> Result<(), TimelockError> {
+        account.require_auth();
+
+        if !env.storage().instance().has(&DataKey::MinDelay) {
+            return Err(TimelockError::NotInitialized);
+        }
+
+        if !Self::has_role(env.clone(), role, account.clone()) {
+            return Err(TimelockError::Unauthorized);
+        }
+
+        Ok(())
+    }
+
+    fn set_role(env: &Env, role: TimelockRole, account: Address, granted: bool) {
+        let key = DataKey::Role(role, account.clone());
+        if granted {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        env.events()
+            .publish((Symbol::new(env, "role_changed"), account), (role, granted));
+    }
+
+    fn get_pending(env: &Env, op_id: u32) -> Result<Operation, TimelockError> {
+        let operation: Operation = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Operation(op_id))
+            .ok_or(TimelockError::OperationNotFound)?;
+
+        if operation.status != OperationStatus::Pending {
+            return Err(TimelockError::OperationNotPending);
+        }
+
+        Ok(operation)
+    }
+}
+
+#[cfg(test)]
+mod test;
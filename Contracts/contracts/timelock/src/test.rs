@@ -0,0 +1,194 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use ::token::{TokenContract, TokenContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Env, IntoVal, String,
+};
+
+const DELAY: u64 = 3600;
+
+/// Deploy the Stellara token with the timelock as its admin
+fn create_token<'a>(env: &Env, admin: &Address) -> TokenContractClient<'a> {
+    let token = TokenContractClient::new(env, &env.register_contract(None, TokenContract));
+    token.initialize(
+        admin,
+        &7,
+        &String::from_str(env, "Stellara"),
+        &String::from_str(env, "STLR"),
+    );
+    token
+}
+
+fn setup_contract(env: &Env) -> (TimelockControllerClient<'_>, Address, Address) {
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let contract_id = env.register_contract(None, TimelockController);
+    let client = TimelockControllerClient::new(env, &contract_id);
+
+    let proposer = Address::generate(env);
+    let executor = Address::generate(env);
+
+    env.mock_all_auths();
+    client.initialize(
+        &vec![env, proposer.clone()],
+        &vec![env, executor.clone()],
+        &DELAY,
+    );
+
+    (client, proposer, executor)
+}
+
+fn mint_action(env: &Env, token: &Address, minter: &Address, to: &Address, amount: i128) -> Action {
+    Action::Call(
+        token.clone(),
+        Symbol::new(env, "mint"),
+        vec![
+            env,
+            minter.into_val(env),
+            to.into_val(env),
+            amount.into_val(env),
+        ],
+    )
+}
+
+#[test]
+fn test_timelock_initialization() {
+    let env = Env::default();
+    let (client, proposer, executor) = setup_contract(&env);
+
+    // Both initial role holders signed their enrollment
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(signers, std::vec![proposer.clone(), executor.clone()]);
+
+    assert_eq!(client.get_min_delay(), DELAY);
+    assert!(client.has_role(&TimelockRole::Proposer, &proposer));
+    assert!(client.has_role(&TimelockRole::Executor, &executor));
+    assert!(!client.has_role(&TimelockRole::Executor, &proposer));
+
+    let result = client.try_initialize(&Vec::new(&env), &Vec::new(&env), &0);
+    assert_eq!(result, Err(Ok(TimelockError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_call_executes_after_delay() {
+    let env = Env::default();
+    let (client, proposer, executor) = setup_contract(&env);
+
+    let token = create_token(&env, &client.address);
+    let holder = Address::generate(&env);
+
+    let action = mint_action(&env, &token.address, &client.address, &holder, 7);
+    let op_id = client.schedule(&proposer, &action, &DELAY);
+    assert!(!client.is_ready(&op_id));
+
+    let result = client.try_execute(&executor, &op_id);
+    assert_eq!(result.err(), Some(Ok(TimelockError::TimelockNotExpired)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + DELAY);
+    assert!(client.is_ready(&op_id));
+
+    client.execute(&executor, &op_id);
+    assert_eq!(token.balance(&holder), 7);
+    assert_eq!(
+        client.get_operation(&op_id).unwrap().status,
+        OperationStatus::Executed
+    );
+
+    let result = client.try_execute(&executor, &op_id);
+    assert_eq!(result.err(), Some(Ok(TimelockError::OperationNotPending)));
+}
+
+#[test]
+fn test_delay_below_minimum_rejected() {
+    let env = Env::default();
+    let (client, proposer, _executor) = setup_contract(&env);
+
+    let result = client.try_schedule(&proposer, &Action::SetMinDelay(0), &(DELAY - 1));
+    assert_eq!(result, Err(Ok(TimelockError::DelayTooShort)));
+}
+
+#[test]
+fn test_delay_overflow_rejected() {
+    let env = Env::default();
+    let (client, proposer, _executor) = setup_contract(&env);
+
+    let result = client.try_schedule(&proposer, &Action::SetMinDelay(0), &u64::MAX);
+    assert_eq!(result, Err(Ok(TimelockError::DelayOverflow)));
+    assert_eq!(client.get_operation(&0), None);
+}
+
+#[test]
+fn test_empty_role_sets_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TimelockController);
+    let client = TimelockControllerClient::new(&env, &contract_id);
+    let account = Address::generate(&env);
+
+    let result = client.try_initialize(&Vec::new(&env), &vec![&env, account.clone()], &DELAY);
+    assert_eq!(result, Err(Ok(TimelockError::NoProposers)));
+
+    let result = client.try_initialize(&vec![&env, account.clone()], &Vec::new(&env), &DELAY);
+    assert_eq!(result, Err(Ok(TimelockError::NoExecutors)));
+
+    // The same account may hold both roles
+    client.initialize(
+        &vec![&env, account.clone()],
+        &vec![&env, account.clone()],
+        &DELAY,
+    );
+    assert!(client.has_role(&TimelockRole::Proposer, &account));
+    assert!(client.has_role(&TimelockRole::Executor, &account));
+}
+
+#[test]
+fn test_role_split_enforced() {
+    let env = Env::default();
+    let (client, proposer, executor) = setup_contract(&env);
+
+    let result = client.try_schedule(&executor, &Action::SetMinDelay(0), &DELAY);
+    assert_eq!(result, Err(Ok(TimelockError::Unauthorized)));
+
+    let op_id = client.schedule(&proposer, &Action::SetMinDelay(0), &DELAY);
+    env.ledger().with_mut(|li| li.timestamp = 1000 + DELAY);
+
+    let result = client.try_execute(&proposer, &op_id);
+    assert_eq!(result.err(), Some(Ok(TimelockError::Unauthorized)));
+}
+
+#[test]
+fn test_cancelled_operation_cannot_execute() {
+    let env = Env::default();
+    let (client, proposer, executor) = setup_contract(&env);
+
+    let op_id = client.schedule(&proposer, &Action::SetMinDelay(0), &DELAY);
+    client.cancel(&proposer, &op_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + DELAY);
+    let result = client.try_execute(&executor, &op_id);
+    assert_eq!(result.err(), Some(Ok(TimelockError::OperationNotPending)));
+    assert_eq!(client.get_min_delay(), DELAY);
+}
+
+#[test]
+fn test_role_changes_go_through_delay() {
+    let env = Env::default();
+    let (client, proposer, executor) = setup_contract(&env);
+    let new_proposer = Address::generate(&env);
+
+    let op_id = client.schedule(
+        &proposer,
+        &Action::GrantRole(TimelockRole::Proposer, new_proposer.clone()),
+        &DELAY,
+    );
+    assert!(!client.has_role(&TimelockRole::Proposer, &new_proposer));
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + DELAY);
+    client.execute(&executor, &op_id);
+    assert!(client.has_role(&TimelockRole::Proposer, &new_proposer));
+}
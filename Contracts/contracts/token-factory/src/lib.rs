@@ -0,0 +1,246 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, xdr::ToXdr, Address, BytesN, Env, IntoVal,
+    String, Symbol, Vec,
+};
+
+// Contract Errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FactoryError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    AlreadyDeployed = 4,
+    InitializeFailed = 5,
+}
+
+// Storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    TokenWasmHash,
+    DeploymentCount,
+    Deployment(Address), // Deployment record per token address
+    DeploymentAt(u32),   // Registry index -> token address
+}
+
+// Deployment record kept in the on-chain registry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deployment {
+    pub token: Address,
+    pub deployer: Address,
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+    pub deployed_at: u32, // Ledger sequence of deployment
+}
+
+/// Deploys token contract instances and initializes them in the same
+/// transaction, so no one can front-run `initialize` on a fresh instance.
+#[contract]
+pub struct TokenFactoryContract;
+
+#[contractimpl]
+impl TokenFactoryContract {
+    // ========== INITIALIZATION ==========
+
+    /// Initialize the factory with its admin and the token wasm to deploy
+    pub fn initialize(env: Env, admin: Address, wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(FactoryError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenWasmHash, &wasm_hash);
+        env.storage()
+            .instance()
+            .set(&DataKey::DeploymentCount, &0u32);
+
+        Ok(())
+    }
+
+    // ========== ADMIN FUNCTIONS ==========
+
+    /// Point future deployments at a new token wasm (existing instances are untouched)
+    pub fn set_wasm_hash(
+        env: Env,
+        admin: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), FactoryError> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenWasmHash, &wasm_hash);
+
+        env.events()
+            .publish((Symbol::new(&env, "wasm_updated"),), wasm_hash);
+
+        Ok(())
+    }
+
+    // ========== DEPLOYMENT ==========
+
+    /// Deploy a new token instance and call its
+    /// `initialize(token_admin, decimals, name, symbol)`.
+    ///
+    /// The salt is namespaced by the deployer, so nobody else can claim the
+    /// address a deployer computed with `deployed_address`.
+    pub fn deploy(
+        env: Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_admin: Address,
+        decimals: u32,
+        name: String,
+        symbol: String,
+    ) -> Result<Address, FactoryError> {
+        deployer.require_auth();
+
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenWasmHash)
+            .ok_or(FactoryError::NotInitialized)?;
+
+        let predicted = Self::deployed_address(env.clone(), deployer.clone(), salt.clone());
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Deployment(predicted))
+        {
+            return Err(FactoryError::AlreadyDeployed);
+        }
+
+        let token = env
+            .deployer()
+            .with_current_contract(Self::namespaced_salt(&env, &deployer, &salt))
+            .deploy(wasm_hash.clone());
+
+        // Initialize atomically: returning an error rolls the deployment back too
+        let initialized = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &token,
+            &Symbol::new(&env, "initialize"),
+            (token_admin.clone(), decimals, name, symbol).into_val(&env),
+        );
+        if !matches!(initialized, Ok(Ok(()))) {
+            return Err(FactoryError::InitializeFailed);
+        }
+
+        let deployment = Deployment {
+            token: token.clone(),
+            deployer: deployer.clone(),
+            admin: token_admin.clone(),
+            wasm_hash,
+            deployed_at: env.ledger().sequence(),
+        };
+
+        let index: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DeploymentCount)
+            .unwrap_or(0);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Deployment(token.clone()), &deployment);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DeploymentAt(index), &token);
+        env.storage()
+            .instance()
+            .set(&DataKey::DeploymentCount, &(index + 1));
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "token_deployed"), deployer),
+            (token.clone(), token_admin),
+        );
+
+        Ok(token)
+    }
+
+    // ========== QUERIES ==========
+
+    /// Address a deployment by `deployer` with `salt` will (or did) land at
+    pub fn deployed_address(env: Env, deployer: Address, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(Self::namespaced_salt(&env, &deployer, &salt))
+            .deployed_address()
+    }
+
+    /// Get the registry record for a deployed token
+    pub fn get_deployment(env: Env, token: Address) -> Option<Deployment> {
+        env.storage().persistent().get(&DataKey::Deployment(token))
+    }
+
+    /// Get the number of tokens deployed through this factory
+    pub fn deployment_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::DeploymentCount)
+            .unwrap_or(0)
+    }
+
+    /// Get a page of deployments in deployment order
+    pub fn deployments(env: Env, start: u32, limit: u32) -> Vec<Deployment> {
+        let count = Self::deployment_count(env.clone());
+        let end = start.saturating_add(limit).min(count);
+
+        let mut page = Vec::new(&env);
+        for index in start..end {
+            let token: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::DeploymentAt(index))
+                .unwrap();
+            let deployment: Deployment = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Deployment(token))
+                .unwrap();
+            page.push_back(deployment);
+        }
+
+        page
+    }
+
+    /// Get the token wasm hash used for new deployments
+    pub fn get_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::TokenWasmHash)
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn namespaced_salt(env: &Env, deployer: &Address, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage = deployer.clone().to_xdr(env);
+        preimage.append(&salt.clone().into());
+        env.crypto().sha256(&preimage)
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), FactoryError> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(FactoryError::NotInitialized)?;
+
+        if admin != &stored_admin {
+            return Err(FactoryError::Unauthorized);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Env, String};
+
+fn setup_contract(env: &Env) -> (TokenFactoryContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, TokenFactoryContract);
+    let client = TokenFactoryContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+
+    env.mock_all_auths();
+    client.initialize(&admin, &BytesN::from_array(env, &[1; 32]));
+
+    (client, admin)
+}
+
+#[test]
+fn test_factory_initialization() {
+    let env = Env::default();
+    let (client, _admin) = setup_contract(&env);
+
+    assert_eq!(
+        client.get_wasm_hash(),
+        Some(BytesN::from_array(&env, &[1; 32]))
+    );
+    assert_eq!(client.deployment_count(), 0);
+    assert_eq!(client.deployments(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_factory_cannot_be_initialized_twice() {
+    let env = Env::default();
+    let (client, admin) = setup_contract(&env);
+
+    let result = client.try_initialize(&admin, &BytesN::from_array(&env, &[2; 32]));
+    assert_eq!(result, Err(Ok(FactoryError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_only_admin_can_set_wasm_hash() {
+    let env = Env::default();
+    let (client, admin) = setup_contract(&env);
+    let attacker = Address::generate(&env);
+
+    let new_hash = BytesN::from_array(&env, &[2; 32]);
+
+    let result = client.try_set_wasm_hash(&attacker, &new_hash);
+    assert_eq!(result, Err(Ok(FactoryError::Unauthorized)));
+
+    client.set_wasm_hash(&admin, &new_hash);
+    assert_eq!(client.get_wasm_hash(), Some(new_hash));
+}
+
+#[test]
+fn test_deployed_address_is_namespaced_by_deployer() {
+    let env = Env::default();
+    let (client, _admin) = setup_contract(&env);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+
+    let alice_address = client.deployed_address(&alice, &salt);
+
+    // Deterministic for the same deployer and salt
+    assert_eq!(alice_address, client.deployed_address(&alice, &salt));
+
+    // Another deployer reusing the salt gets a different address
+    assert_ne!(alice_address, client.deployed_address(&bob, &salt));
+}
+
+mod token_wasm {
+    // Release build of contracts/token; rebuild it when the token's interface changes
+    soroban_sdk::contractimport!(file = "testdata/token.wasm");
+}
+
+fn setup_with_token_wasm(env: &Env) -> TokenFactoryContractClient<'_> {
+    let (client, admin) = setup_contract(env);
+    // `token_admin` signs the nested `initialize`, not the factory call itself
+    env.mock_all_auths_allowing_non_root_auth();
+    let wasm_hash = env.deployer().upload_contract_wasm(token_wasm::WASM);
+    client.set_wasm_hash(&admin, &wasm_hash);
+    client
+}
+
+#[test]
+fn test_deploy_initializes_and_records_token() {
+    let env = Env::default();
+    let client = setup_with_token_wasm(&env);
+
+    let deployer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[3; 32]);
+
+    let token = client.deploy(
+        &deployer,
+        &salt,
+        &token_admin,
+        &7,
+        &String::from_str(&env, "Stellara"),
+        &String::from_str(&env, "STLR"),
+    );
+    assert_eq!(token, client.deployed_address(&deployer, &salt));
+
+    // The instance was initialized in the same call
+    let token_client = token_wasm::Client::new(&env, &token);
+    assert_eq!(token_client.get_admin(), Some(token_admin.clone()));
+    assert_eq!(token_client.symbol(), String::from_str(&env, "STLR"));
+
+    let deployment = client.get_deployment(&token).unwrap();
+    assert_eq!(deployment.deployer, deployer);
+    assert_eq!(deployment.admin, token_admin);
+    assert_eq!(deployment.wasm_hash, client.get_wasm_hash().unwrap());
+    assert_eq!(client.deployment_count(), 1);
+    assert_eq!(client.deployments(&0, &10), soroban_sdk::vec![&env, deployment]);
+}
+
+#[test]
+fn test_deploy_rejects_reused_salt() {
+    let env = Env::default();
+    let client = setup_with_token_wasm(&env);
+
+    let deployer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[3; 32]);
+    let name = String::from_str(&env, "Stellara");
+    let symbol = String::from_str(&env, "STLR");
+
+    client.deploy(&deployer, &salt, &token_admin, &7, &name, &symbol);
+
+    let result = client.try_deploy(&deployer, &salt, &token_admin, &7, &name, &symbol);
+    assert_eq!(result, Err(Ok(FactoryError::AlreadyDeployed)));
+    assert_eq!(client.deployment_count(), 1);
+}
+
+#[test]
+fn test_failed_initialize_rolls_back_deployment() {
+    let env = Env::default();
+    let client = setup_with_token_wasm(&env);
+
+    let deployer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[3; 32]);
+    let symbol = String::from_str(&env, "STLR");
+
+    // The token rejects an empty name
+    let result = client.try_deploy(
+        &deployer,
+        &salt,
+        &token_admin,
+        &7,
+        &String::from_str(&env, ""),
+        &symbol,
+    );
+    assert_eq!(result, Err(Ok(FactoryError::InitializeFailed)));
+    assert_eq!(client.deployment_count(), 0);
+
+    // Nothing was left at the address, so the salt is still usable
+    let token = client.deploy(
+        &deployer,
+        &salt,
+        &token_admin,
+        &7,
+        &String::from_str(&env, "Stellara"),
+        &symbol,
+    );
+    assert_eq!(token, client.deployed_address(&deployer, &salt));
+    assert_eq!(client.deployment_count(), 1);
+}
@@ -1,7 +1,97 @@
-use soroban_sdk::{Env, Address};
-use crate::storage::get_admin;
+use soroban_sdk::{contracttype, Env, Address, Vec};
+use crate::error::TokenError;
+use crate::storage;
 
-pub fn require_admin(env: &Env) {
-    let admin = get_admin(env);
+/// Dead-man switch: who may claim the admin role, and after how long a silence
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Succession {
+    pub successor: Address,
+    pub heartbeat_ledgers: u32,
+}
+
+/// Permission the admin can delegate to other accounts
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Role {
+    Minter = 0,   // Can mint new units
+    Upgrader = 1, // Can replace the contract wasm
+}
+
+/// Configuration call batched through `admin_multicall`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminOp {
+    SetMintLimit(i128, u32),
+    RemoveMintLimit,
+    SetSuccessor(Address, u32),
+    ClearSuccessor,
+    SetGuardians(Vec<Address>, u32, u32),
+    GrantRole(Role, Address),
+    RevokeRole(Role, Address),
+}
+
+pub fn get_admin(env: &Env) -> Result<Address, TokenError> {
+    storage::get_admin(env).ok_or(TokenError::NotInitialized)
+}
+
+pub fn require_admin(env: &Env) -> Result<Address, TokenError> {
+    let admin = get_admin(env)?;
     admin.require_auth();
+
+    // Any authorized admin call counts as a heartbeat
+    storage::set_last_active(env, env.ledger().sequence());
+
+    Ok(admin)
+}
+
+/// The admin holds every role; other accounts need an explicit grant.
+pub fn require_role(env: &Env, role: Role, account: &Address) -> Result<(), TokenError> {
+    account.require_auth();
+
+    if &get_admin(env)? == account {
+        storage::set_last_active(env, env.ledger().sequence());
+        return Ok(());
+    }
+    if !storage::has_role(env, role, account) {
+        return Err(TokenError::MissingRole);
+    }
+
+    Ok(())
+}
+
+pub fn claim_admin(env: &Env, successor: &Address) -> Result<(), TokenError> {
+    successor.require_auth();
+
+    let succession = storage::get_succession(env).ok_or(TokenError::NoSuccessor)?;
+    if &succession.successor != successor {
+        return Err(TokenError::Unauthorized);
+    }
+
+    let deadline = storage::get_last_active(env).saturating_add(succession.heartbeat_ledgers);
+    if env.ledger().sequence() <= deadline {
+        return Err(TokenError::AdminStillActive);
+    }
+
+    replace_admin(env, successor);
+
+    Ok(())
+}
+
+pub fn accept_admin(env: &Env) -> Result<Address, TokenError> {
+    let pending = storage::get_pending_admin(env).ok_or(TokenError::NoPendingAdmin)?;
+    pending.require_auth();
+
+    replace_admin(env, &pending);
+
+    Ok(pending)
+}
+
+/// Install a new admin, dropping the handoff and successor the old admin had set.
+pub fn replace_admin(env: &Env, new_admin: &Address) {
+    storage::set_admin(env, new_admin);
+    storage::remove_pending_admin(env);
+    storage::remove_succession(env);
+    storage::set_last_active(env, env.ledger().sequence());
 }
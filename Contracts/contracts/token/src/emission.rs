@@ -0,0 +1,80 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use crate::error::TokenError;
+use crate::{fungible, storage};
+
+/// Units minted to `to` when an epoch is advanced
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allocation {
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Fixed inflation schedule, one allocation list per epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionSchedule {
+    pub epoch_ledgers: u32,
+    pub epochs: Vec<Vec<Allocation>>,
+    pub next_epoch: u32,
+    pub next_due: u32,
+}
+
+pub fn set_schedule(env: &Env, epoch_ledgers: u32, epochs: Vec<Vec<Allocation>>) -> Result<EmissionSchedule, TokenError> {
+    // Once set the schedule cannot be changed, not even by the admin
+    if storage::get_emission(env).is_some() {
+        return Err(TokenError::EmissionAlreadySet);
+    }
+    if epoch_ledgers == 0 {
+        return Err(TokenError::InvalidEpochLength);
+    }
+    // An empty schedule would disable emission for good, since it cannot be replaced
+    if epochs.is_empty() {
+        return Err(TokenError::EmptyEmission);
+    }
+    for allocations in epochs.iter() {
+        if allocations.is_empty() {
+            return Err(TokenError::EmptyEmission);
+        }
+        for allocation in allocations.iter() {
+            if allocation.amount <= 0 {
+                return Err(TokenError::InvalidAmount);
+            }
+        }
+    }
+
+    let schedule = EmissionSchedule {
+        epoch_ledgers,
+        epochs,
+        next_epoch: 0,
+        next_due: env.ledger().sequence().saturating_add(epoch_ledgers),
+    };
+    storage::set_emission(env, &schedule);
+
+    Ok(schedule)
+}
+
+/// Mint the allocations of the next due epoch.
+pub fn advance(env: &Env) -> Result<(u32, Vec<Allocation>), TokenError> {
+    let mut schedule = storage::get_emission(env).ok_or(TokenError::NoEmissionSchedule)?;
+
+    if schedule.next_epoch >= schedule.epochs.len() {
+        return Err(TokenError::EmissionFinished);
+    }
+    if env.ledger().sequence() < schedule.next_due {
+        return Err(TokenError::EpochNotDue);
+    }
+
+    let epoch = schedule.next_epoch;
+    let allocations = schedule.epochs.get(epoch).unwrap();
+    for allocation in allocations.iter() {
+        // Not charged to the mint limit, which exists to cap minters
+        fungible::receive_balance(env, &allocation.to, allocation.amount)?;
+    }
+
+    schedule.next_epoch += 1;
+    schedule.next_due = schedule.next_due.saturating_add(schedule.epoch_ledgers);
+    storage::set_emission(env, &schedule);
+
+    Ok((epoch, allocations))
+}
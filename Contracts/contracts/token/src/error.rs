@@ -0,0 +1,42 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    AlreadyInitialized = 1,
+    InvalidAdmin = 2,
+    NotInitialized = 3,
+    Unauthorized = 4,
+    Overflow = 5,
+    InvalidAmount = 6,
+    InvalidRecipient = 7,
+    InsufficientBalance = 8,
+    InsufficientAllowance = 9,
+    InvalidExpiration = 10,
+    CannotRescueOwnToken = 11,
+    InvalidMetadata = 12,
+    MetadataNotSet = 13,
+    InvalidHeartbeat = 14,
+    NoSuccessor = 15,
+    AdminStillActive = 16,
+    InvalidThreshold = 17,
+    NoGuardians = 18,
+    RecoveryPending = 19,
+    NoRecoveryPending = 20,
+    AlreadyApproved = 21,
+    NotEnoughApprovals = 22,
+    RecoveryDelayNotPassed = 23,
+    InvalidWindow = 24,
+    MintLimitExceeded = 25,
+    InvalidEpochLength = 26,
+    EmissionAlreadySet = 27,
+    NoEmissionSchedule = 28,
+    EmissionFinished = 29,
+    EpochNotDue = 30,
+    MissingRole = 31,
+    NoPendingAdmin = 32,
+    AlreadyMigrated = 33,
+    DuplicateGuardian = 34,
+    EmptyEmission = 35,
+}
@@ -0,0 +1,179 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String, Symbol};
+use crate::error::TokenError;
+use crate::storage;
+
+const MAX_NAME_LEN: u32 = 32;
+const MAX_SYMBOL_LEN: u32 = 12;
+
+/// SEP-41 allowance granted by an owner to a spender
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Token name, symbol and decimals reported to wallets
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Metadata {
+    pub decimals: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// SEP-41 signatures cannot return errors, so they trap with the error code instead.
+pub fn or_trap<T>(env: &Env, result: Result<T, TokenError>) -> T {
+    result.unwrap_or_else(|error| panic_with_error!(env, error))
+}
+
+pub fn check_nonnegative(amount: i128) -> Result<(), TokenError> {
+    if amount < 0 {
+        return Err(TokenError::InvalidAmount);
+    }
+
+    Ok(())
+}
+
+pub fn receive_balance(env: &Env, owner: &Address, amount: i128) -> Result<(), TokenError> {
+    let balance = storage::balance_of(env, owner)
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    storage::set_balance(env, owner, &balance);
+
+    Ok(())
+}
+
+pub fn spend_balance(env: &Env, owner: &Address, amount: i128) -> Result<(), TokenError> {
+    let balance = storage::balance_of(env, owner);
+    if balance < amount {
+        return Err(TokenError::InsufficientBalance);
+    }
+    storage::set_balance(env, owner, &(balance - amount));
+
+    Ok(())
+}
+
+pub fn read_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    match storage::get_allowance(env, from, spender) {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+        // Expired allowances read as zero
+        _ => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+pub fn approve(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) -> Result<(), TokenError> {
+    check_nonnegative(amount)?;
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        return Err(TokenError::InvalidExpiration);
+    }
+
+    storage::set_allowance(
+        env,
+        from,
+        spender,
+        &AllowanceValue {
+            amount,
+            expiration_ledger,
+        },
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "approve"), from.clone(), spender.clone()),
+        (amount, expiration_ledger),
+    );
+
+    Ok(())
+}
+
+pub fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) -> Result<(), TokenError> {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        return Err(TokenError::InsufficientAllowance);
+    }
+
+    if amount > 0 {
+        storage::set_allowance(
+            env,
+            from,
+            spender,
+            &AllowanceValue {
+                amount: allowance.amount - amount,
+                expiration_ledger: allowance.expiration_ledger,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+pub fn move_balance(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), TokenError> {
+    check_nonnegative(amount)?;
+    spend_balance(env, from, amount)?;
+    receive_balance(env, to, amount)?;
+
+    env.events()
+        .publish((Symbol::new(env, "transfer"), from.clone(), to.clone()), amount);
+
+    Ok(())
+}
+
+pub fn burn_balance(env: &Env, from: &Address, amount: i128) -> Result<(), TokenError> {
+    check_nonnegative(amount)?;
+    spend_balance(env, from, amount)?;
+
+    env.events()
+        .publish((Symbol::new(env, "burn"), from.clone()), amount);
+
+    Ok(())
+}
+
+/// Run the checks `transfer` (or `transfer_from` when `spender` is set) would, without moving funds.
+pub fn preview_transfer(
+    env: &Env,
+    spender: Option<&Address>,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), TokenError> {
+    check_nonnegative(amount)?;
+    if let Some(spender) = spender {
+        if read_allowance(env, from, spender).amount < amount {
+            return Err(TokenError::InsufficientAllowance);
+        }
+    }
+    if storage::balance_of(env, from) < amount {
+        return Err(TokenError::InsufficientBalance);
+    }
+    if from != to {
+        storage::balance_of(env, to)
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+/// Reject empty or oversized names and symbols before they reach wallets.
+pub fn check_metadata(name: &String, symbol: &String) -> Result<(), TokenError> {
+    if name.len() == 0 || name.len() > MAX_NAME_LEN {
+        return Err(TokenError::InvalidMetadata);
+    }
+    if symbol.len() == 0 || symbol.len() > MAX_SYMBOL_LEN {
+        return Err(TokenError::InvalidMetadata);
+    }
+    Ok(())
+}
+
+pub fn read_metadata(env: &Env) -> Result<Metadata, TokenError> {
+    storage::get_metadata(env).ok_or(TokenError::MetadataNotSet)
+}
@@ -1,30 +1,598 @@
-use soroban_sdk::{contract, contractimpl, Env, Address};
+use soroban_sdk::{contract, contractimpl, token::{self, TokenInterface}, BytesN, Env, Address, IntoVal, String, Symbol, Vec};
 
 mod admin;
+mod emission;
+mod error;
+mod fungible;
+mod mint_limit;
+mod recovery;
 mod storage;
 
+pub use admin::{AdminOp, Role, Succession};
+pub use emission::{Allocation, EmissionSchedule};
+pub use error::TokenError;
+pub use fungible::{AllowanceValue, Metadata};
+pub use mint_limit::{MintLimit, MintWindow};
+pub use recovery::{GuardianConfig, Recovery};
+
+const CONTRACT_VERSION: u32 = 1;
+
 #[contract]
 pub struct TokenContract;
 
 #[contractimpl]
 impl TokenContract {
 
-    pub fn initialize(env: Env, admin: Address) {
+    /// Set the admin and SEP-41 metadata once. Deploy through the token factory
+    /// so this runs in the same transaction as the deployment and cannot be front-run.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        decimals: u32,
+        name: String,
+        symbol: String,
+    ) -> Result<(), TokenError> {
         if storage::has_admin(&env) {
-            panic!("Already initialized");
+            return Err(TokenError::AlreadyInitialized);
+        }
+        if admin == env.current_contract_address() {
+            return Err(TokenError::InvalidAdmin);
         }
+        fungible::check_metadata(&name, &symbol)?;
         admin.require_auth();
         storage::set_admin(&env, &admin);
+        storage::set_metadata(
+            &env,
+            &Metadata {
+                decimals,
+                name,
+                symbol,
+            },
+        );
+        storage::set_last_active(&env, env.ledger().sequence());
+        storage::set_version(&env, CONTRACT_VERSION);
+
+        Ok(())
+    }
+
+    /// Mint new units; `minter` must be the admin or hold the `Minter` role.
+    pub fn mint(env: Env, minter: Address, to: Address, amount: i128) -> Result<(), TokenError> {
+        admin::require_role(&env, Role::Minter, &minter)?;
+        fungible::check_nonnegative(amount)?;
+        mint_limit::consume(&env, amount)?;
+
+        fungible::receive_balance(&env, &to, amount)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "mint"), minter, to), amount);
+
+        Ok(())
+    }
+
+    /// Start handing the admin role to `new_admin`; takes effect once they accept.
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::set_pending_admin(&env, &new_admin);
+
+        env.events()
+            .publish((Symbol::new(&env, "admin_proposed"), admin), new_admin);
+
+        Ok(())
+    }
+
+    pub fn accept_admin(env: Env) -> Result<(), TokenError> {
+        let previous = admin::get_admin(&env)?;
+        let new_admin = admin::accept_admin(&env)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "admin_accepted"), new_admin), previous);
+
+        Ok(())
+    }
+
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+
+        let pending = storage::get_pending_admin(&env).ok_or(TokenError::NoPendingAdmin)?;
+        storage::remove_pending_admin(&env);
+
+        env.events()
+            .publish((Symbol::new(&env, "admin_transfer_cancelled"),), pending);
+
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Option<Address> {
+        storage::get_admin(&env)
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        storage::get_pending_admin(&env)
+    }
+
+    pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+        Self::apply_admin_op(&env, AdminOp::GrantRole(role, account))
+    }
+
+    pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+        Self::apply_admin_op(&env, AdminOp::RevokeRole(role, account))
+    }
+
+    /// True if `account` holds `role` explicitly or is the admin.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        storage::get_admin(&env) == Some(account.clone()) || storage::has_role(&env, role, &account)
+    }
+
+    /// Replace the contract wasm, keeping all storage. Call `migrate` afterwards.
+    pub fn upgrade(env: Env, upgrader: Address, new_wasm_hash: BytesN<32>) -> Result<(), TokenError> {
+        admin::require_role(&env, Role::Upgrader, &upgrader)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events()
+            .publish((Symbol::new(&env, "upgraded"), upgrader), new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Bring stored data up to the schema of the running wasm, one version at a time.
+    pub fn migrate(env: Env) -> Result<u32, TokenError> {
+        admin::require_admin(&env)?;
+
+        let from = storage::get_version(&env);
+        if from >= CONTRACT_VERSION {
+            return Err(TokenError::AlreadyMigrated);
+        }
+
+        for version in (from + 1)..=CONTRACT_VERSION {
+            Self::migrate_to(&env, version);
+        }
+        storage::set_version(&env, CONTRACT_VERSION);
+
+        env.events()
+            .publish((Symbol::new(&env, "migrated"),), (from, CONTRACT_VERSION));
+
+        Ok(CONTRACT_VERSION)
+    }
+
+    /// Get the storage schema version
+    pub fn get_version(env: Env) -> u32 {
+        storage::get_version(&env)
+    }
+
+    /// Move a foreign SEP-41 token balance that was sent to this contract by mistake.
+    pub fn rescue_token(env: Env, token_contract: Address, amount: i128, to: Address) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+
+        // Balances of this token are ledger entries, not assets held by the contract
+        if token_contract == env.current_contract_address() {
+            return Err(TokenError::CannotRescueOwnToken);
+        }
+        if amount <= 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+
+        token::Client::new(&env, &token_contract).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        env.events()
+            .publish((Symbol::new(&env, "rescue_token"), token_contract), (to, amount));
+
+        Ok(())
+    }
+
+    /// Move a foreign NFT that was sent to this contract by mistake.
+    ///
+    /// The NFT contract must expose `transfer(from, to, token_id)`.
+    pub fn rescue_nft(env: Env, token_contract: Address, token_id: u64, to: Address) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+
+        if token_contract == env.current_contract_address() {
+            return Err(TokenError::CannotRescueOwnToken);
+        }
+
+        env.invoke_contract::<()>(
+            &token_contract,
+            &Symbol::new(&env, "transfer"),
+            (env.current_contract_address(), to.clone(), token_id).into_val(&env),
+        );
+
+        env.events()
+            .publish((Symbol::new(&env, "rescue_nft"), token_contract), (to, token_id));
+
+        Ok(())
+    }
+
+    /// Move this token's own units that ended up credited to the contract address.
+    ///
+    /// Only the contract's own balance can be touched; nothing else is escrowed.
+    /// Deployments wanting a delay should make the timelock controller the admin.
+    pub fn recover_own_tokens(env: Env, amount: i128, to: Address) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+
+        let contract = env.current_contract_address();
+        if to == contract {
+            return Err(TokenError::InvalidRecipient);
+        }
+        if amount <= 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+
+        // Emits the SEP-41 transfer event so indexers stay in sync
+        fungible::move_balance(&env, &contract, &to, amount)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "recover_own"), to), amount);
+
+        Ok(())
+    }
+
+    /// Designate who may take over as admin after `heartbeat_ledgers` of admin inactivity.
+    pub fn set_successor(env: Env, successor: Address, heartbeat_ledgers: u32) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+        Self::apply_admin_op(&env, AdminOp::SetSuccessor(successor, heartbeat_ledgers))
+    }
+
+    pub fn clear_successor(env: Env) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+        Self::apply_admin_op(&env, AdminOp::ClearSuccessor)
+    }
+
+    /// Prove the admin key is still alive without changing anything.
+    pub fn heartbeat(env: Env) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+
+        Ok(())
+    }
+
+    /// Take over the admin role once the current admin has been silent too long.
+    pub fn claim_admin(env: Env, successor: Address) -> Result<(), TokenError> {
+        let previous = admin::get_admin(&env)?;
+        admin::claim_admin(&env, &successor)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "admin_claimed"), successor), previous);
+
+        Ok(())
+    }
+
+    pub fn get_successor(env: Env) -> Option<Succession> {
+        storage::get_succession(&env)
+    }
+
+    /// Ledger sequence of the last authorized admin call.
+    pub fn last_admin_activity(env: Env) -> u32 {
+        storage::get_last_active(&env)
+    }
+
+    /// Configure the guardians that can jointly rotate the admin after `delay_ledgers`.
+    pub fn set_guardians(env: Env, guardians: Vec<Address>, threshold: u32, delay_ledgers: u32) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+        Self::apply_admin_op(&env, AdminOp::SetGuardians(guardians, threshold, delay_ledgers))
+    }
+
+    /// Guardian proposes rotating the admin to `new_admin`; counts as their approval.
+    pub fn propose_recovery(env: Env, guardian: Address, new_admin: Address) -> Result<(), TokenError> {
+        recovery::propose(&env, &guardian, &new_admin)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "recovery_proposed"), guardian), new_admin);
+
+        Ok(())
+    }
+
+    /// Guardian backs the recovery towards `new_admin`, moving any earlier approval.
+    pub fn approve_recovery(env: Env, guardian: Address, new_admin: Address) -> Result<(), TokenError> {
+        let pending = recovery::approve(&env, &guardian, &new_admin)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "recovery_approved"), guardian),
+            (new_admin, pending.ready_at),
+        );
+
+        Ok(())
+    }
+
+    /// Guardian withdraws their approval, e.g. from a mistyped proposal.
+    pub fn withdraw_recovery(env: Env, guardian: Address) -> Result<(), TokenError> {
+        recovery::withdraw(&env, &guardian)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "recovery_withdrawn"), guardian), ());
+
+        Ok(())
+    }
+
+    /// Current admin vetoes every pending recovery during the delay window.
+    pub fn cancel_recovery(env: Env) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+
+        if storage::get_recoveries(&env).is_empty() {
+            return Err(TokenError::NoRecoveryPending);
+        }
+        storage::remove_recoveries(&env);
+
+        env.events()
+            .publish((Symbol::new(&env, "recovery_cancelled"),), ());
+
+        Ok(())
+    }
+
+    /// Apply the approved recovery towards `new_admin` once its delay has passed. Anyone may call.
+    pub fn execute_recovery(env: Env, new_admin: Address) -> Result<(), TokenError> {
+        let previous = admin::get_admin(&env)?;
+        recovery::execute(&env, &new_admin)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "admin_recovered"), new_admin), previous);
+
+        Ok(())
+    }
+
+    pub fn get_guardians(env: Env) -> Option<GuardianConfig> {
+        storage::get_guardian_config(&env)
+    }
+
+    /// Get the pending recovery towards `new_admin`
+    pub fn get_recovery(env: Env, new_admin: Address) -> Option<Recovery> {
+        storage::get_recoveries(&env).get(new_admin)
+    }
+
+    /// Get every pending recovery, one per candidate admin
+    pub fn get_recoveries(env: Env) -> Vec<Recovery> {
+        storage::get_recoveries(&env).values()
+    }
+
+    /// Cap minting at `max_per_window` units per `window_ledgers` ledgers.
+    pub fn set_mint_limit(env: Env, max_per_window: i128, window_ledgers: u32) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+        Self::apply_admin_op(&env, AdminOp::SetMintLimit(max_per_window, window_ledgers))
+    }
+
+    pub fn remove_mint_limit(env: Env) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+        Self::apply_admin_op(&env, AdminOp::RemoveMintLimit)
+    }
+
+    pub fn get_mint_limit(env: Env) -> Option<MintLimit> {
+        storage::get_mint_limit(&env)
+    }
+
+    /// Units minted so far in the current window.
+    pub fn minted_in_window(env: Env) -> i128 {
+        match (storage::get_mint_limit(&env), storage::get_mint_window(&env)) {
+            (Some(limit), Some(window))
+                if env.ledger().sequence() < window.start.saturating_add(limit.window_ledgers) =>
+            {
+                window.minted
+            }
+            _ => 0,
+        }
+    }
+
+    /// Fix the emission schedule: `epochs[i]` is minted once epoch `i` is due.
+    ///
+    /// Can only be set once, so emission no longer depends on trusting the admin.
+    /// Scheduled emission is not counted against the mint limit.
+    pub fn set_emission_schedule(env: Env, epoch_ledgers: u32, epochs: Vec<Vec<Allocation>>) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
+
+        let schedule = emission::set_schedule(&env, epoch_ledgers, epochs)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "emission_set"),),
+            (schedule.epochs.len(), epoch_ledgers),
+        );
+
+        Ok(())
+    }
+
+    /// Mint the next epoch's allocations once it is due. Anyone may call.
+    pub fn advance_epoch(env: Env) -> Result<u32, TokenError> {
+        let (epoch, allocations) = emission::advance(&env)?;
+
+        for allocation in allocations.iter() {
+            env.events().publish(
+                (Symbol::new(&env, "mint"), env.current_contract_address(), allocation.to.clone()),
+                allocation.amount,
+            );
+            env.events().publish(
+                (Symbol::new(&env, "emission"), allocation.to),
+                (epoch, allocation.amount),
+            );
+        }
+
+        Ok(epoch)
+    }
+
+    pub fn get_emission_schedule(env: Env) -> Option<EmissionSchedule> {
+        storage::get_emission(&env)
+    }
+
+    /// Report the error a transfer would fail with, so wallets can explain it before submitting.
+    ///
+    /// Pass `spender` to preview `transfer_from`; nothing is written and no auth is required.
+    pub fn preview_transfer(
+        env: Env,
+        spender: Option<Address>,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
+        fungible::preview_transfer(&env, spender.as_ref(), &from, &to, amount)
     }
 
-    pub fn mint(env: Env, to: Address, amount: i128) {
-        admin::require_admin(&env);
+    /// Apply several configuration calls in one transaction; any failure reverts them all.
+    pub fn admin_multicall(env: Env, ops: Vec<AdminOp>) -> Result<(), TokenError> {
+        admin::require_admin(&env)?;
 
-        // checked arithmetic
-        let balance = storage::balance_of(&env, &to);
-        let new_balance = balance.checked_add(amount)
-            .expect("Overflow");
+        for op in ops.iter() {
+            Self::apply_admin_op(&env, op)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TokenContract {
+    // Storage changes needed to reach `version`. Version 1 only starts
+    // tracking the schema version; later changes match on `version` here.
+    fn migrate_to(_env: &Env, _version: u32) {}
+
+    // Admin auth must already be checked; it can only be required once per call
+    fn apply_admin_op(env: &Env, op: AdminOp) -> Result<(), TokenError> {
+        match op {
+            AdminOp::SetMintLimit(max_per_window, window_ledgers) => {
+                if max_per_window < 0 {
+                    return Err(TokenError::InvalidAmount);
+                }
+                if window_ledgers == 0 {
+                    return Err(TokenError::InvalidWindow);
+                }
+
+                storage::set_mint_limit(
+                    env,
+                    &MintLimit {
+                        max_per_window,
+                        window_ledgers,
+                    },
+                );
+
+                env.events()
+                    .publish((Symbol::new(env, "mint_limit_set"),), (max_per_window, window_ledgers));
+            }
+            AdminOp::RemoveMintLimit => {
+                storage::remove_mint_limit(env);
+
+                env.events()
+                    .publish((Symbol::new(env, "mint_limit_removed"),), ());
+            }
+            AdminOp::SetSuccessor(successor, heartbeat_ledgers) => {
+                if heartbeat_ledgers == 0 {
+                    return Err(TokenError::InvalidHeartbeat);
+                }
+
+                storage::set_succession(
+                    env,
+                    &Succession {
+                        successor: successor.clone(),
+                        heartbeat_ledgers,
+                    },
+                );
+
+                env.events()
+                    .publish((Symbol::new(env, "successor_set"), successor), heartbeat_ledgers);
+            }
+            AdminOp::ClearSuccessor => {
+                storage::remove_succession(env);
+
+                env.events()
+                    .publish((Symbol::new(env, "successor_cleared"),), ());
+            }
+            AdminOp::SetGuardians(guardians, threshold, delay_ledgers) => {
+                recovery::set_guardians(
+                    env,
+                    &GuardianConfig {
+                        guardians,
+                        threshold,
+                        delay_ledgers,
+                    },
+                )?;
+
+                env.events()
+                    .publish((Symbol::new(env, "guardians_set"),), (threshold, delay_ledgers));
+            }
+            AdminOp::GrantRole(role, account) => {
+                storage::set_role(env, role, &account);
+
+                env.events()
+                    .publish((Symbol::new(env, "role_granted"), role, account), ());
+            }
+            AdminOp::RevokeRole(role, account) => {
+                storage::remove_role(env, role, &account);
+
+                env.events()
+                    .publish((Symbol::new(env, "role_revoked"), role, account), ());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ========== SEP-41 ==========
+
+// The interface signatures are fixed, so failures trap with a `TokenError` code
+#[contractimpl]
+impl TokenInterface for TokenContract {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        fungible::read_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+
+        let result = fungible::approve(&env, &from, &spender, amount, expiration_ledger);
+        fungible::or_trap(&env, result)
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        storage::balance_of(&env, &id)
+    }
 
-        storage::set_balance(&env, &to, &new_balance);
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+
+        let result = fungible::move_balance(&env, &from, &to, amount);
+        fungible::or_trap(&env, result)
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+
+        let result = fungible::check_nonnegative(amount)
+            .and_then(|_| fungible::spend_allowance(&env, &from, &spender, amount))
+            .and_then(|_| fungible::move_balance(&env, &from, &to, amount));
+        fungible::or_trap(&env, result)
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+
+        let result = fungible::burn_balance(&env, &from, amount);
+        fungible::or_trap(&env, result)
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+
+        let result = fungible::check_nonnegative(amount)
+            .and_then(|_| fungible::spend_allowance(&env, &from, &spender, amount))
+            .and_then(|_| fungible::burn_balance(&env, &from, amount));
+        fungible::or_trap(&env, result)
+    }
+
+    fn decimals(env: Env) -> u32 {
+        let result = fungible::read_metadata(&env);
+        fungible::or_trap(&env, result).decimals
+    }
+
+    fn name(env: Env) -> String {
+        let result = fungible::read_metadata(&env);
+        fungible::or_trap(&env, result).name
+    }
+
+    fn symbol(env: Env) -> String {
+        let result = fungible::read_metadata(&env);
+        fungible::or_trap(&env, result).symbol
     }
 }
+
+#[cfg(test)]
+mod test;
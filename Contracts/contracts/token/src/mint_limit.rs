@@ -0,0 +1,43 @@
+use soroban_sdk::{contracttype, Env};
+use crate::error::TokenError;
+use crate::storage;
+
+/// Cap on units minted within a window of ledgers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintLimit {
+    pub max_per_window: i128,
+    pub window_ledgers: u32,
+}
+
+/// Units minted in the current window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintWindow {
+    pub start: u32,
+    pub minted: i128,
+}
+
+/// Charge `amount` against the mint limit, if one is configured.
+pub fn consume(env: &Env, amount: i128) -> Result<(), TokenError> {
+    let limit = match storage::get_mint_limit(env) {
+        Some(limit) => limit,
+        None => return Ok(()),
+    };
+
+    let now = env.ledger().sequence();
+    let mut window = storage::get_mint_window(env).unwrap_or(MintWindow { start: now, minted: 0 });
+    if now >= window.start.saturating_add(limit.window_ledgers) {
+        window = MintWindow { start: now, minted: 0 };
+    }
+
+    let minted = window.minted.checked_add(amount).ok_or(TokenError::Overflow)?;
+    if minted > limit.max_per_window {
+        return Err(TokenError::MintLimitExceeded);
+    }
+    window.minted = minted;
+
+    storage::set_mint_window(env, &window);
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{contracttype, Env, Address, Map, Vec};
+use crate::error::TokenError;
+use crate::{admin, storage};
+
+/// Guardians that can jointly rotate the admin key
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianConfig {
+    pub guardians: Vec<Address>,
+    pub threshold: u32,
+    pub delay_ledgers: u32,
+}
+
+/// Admin rotation proposed by guardians. Each candidate admin gathers its own
+/// approvals, so a bogus proposal cannot block a legitimate one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Recovery {
+    pub new_admin: Address,
+    pub approvals: Vec<Address>,
+    pub ready_at: Option<u32>, // Set once the threshold is reached
+}
+
+pub fn set_guardians(env: &Env, config: &GuardianConfig) -> Result<(), TokenError> {
+    if config.threshold == 0 || config.threshold > config.guardians.len() {
+        return Err(TokenError::InvalidThreshold);
+    }
+    for (index, guardian) in config.guardians.iter().enumerate() {
+        if config.guardians.first_index_of(&guardian) != Some(index as u32) {
+            return Err(TokenError::DuplicateGuardian);
+        }
+    }
+
+    storage::set_guardian_config(env, config);
+
+    // Approvals gathered under the old guardian set no longer apply
+    storage::remove_recoveries(env);
+
+    Ok(())
+}
+
+/// Start a recovery towards `new_admin`; counts as the proposer's approval.
+pub fn propose(env: &Env, guardian: &Address, new_admin: &Address) -> Result<Recovery, TokenError> {
+    require_guardian(env, guardian)?;
+
+    let mut recoveries = storage::get_recoveries(env);
+    if recoveries.contains_key(new_admin.clone()) {
+        return Err(TokenError::RecoveryPending);
+    }
+
+    let recovery = Recovery {
+        new_admin: new_admin.clone(),
+        approvals: Vec::new(env),
+        ready_at: None,
+    };
+    recoveries.set(new_admin.clone(), recovery);
+    record_approval(env, &mut recoveries, guardian, new_admin)
+}
+
+/// Approve the recovery towards `new_admin`, moving any earlier approval.
+pub fn approve(env: &Env, guardian: &Address, new_admin: &Address) -> Result<Recovery, TokenError> {
+    require_guardian(env, guardian)?;
+
+    let mut recoveries = storage::get_recoveries(env);
+    let recovery = recoveries
+        .get(new_admin.clone())
+        .ok_or(TokenError::NoRecoveryPending)?;
+    if recovery.approvals.contains(guardian) {
+        return Err(TokenError::AlreadyApproved);
+    }
+
+    record_approval(env, &mut recoveries, guardian, new_admin)
+}
+
+/// Withdraw a guardian's approval; a proposal nobody approves any more is dropped.
+pub fn withdraw(env: &Env, guardian: &Address) -> Result<(), TokenError> {
+    require_guardian(env, guardian)?;
+
+    let mut recoveries = storage::get_recoveries(env);
+    if !remove_approval(env, &mut recoveries, guardian)? {
+        return Err(TokenError::NoRecoveryPending);
+    }
+    storage::set_recoveries(env, &recoveries);
+
+    Ok(())
+}
+
+pub fn execute(env: &Env, new_admin: &Address) -> Result<(), TokenError> {
+    let recovery = storage::get_recoveries(env)
+        .get(new_admin.clone())
+        .ok_or(TokenError::NoRecoveryPending)?;
+
+    let ready_at = recovery.ready_at.ok_or(TokenError::NotEnoughApprovals)?;
+    if env.ledger().sequence() < ready_at {
+        return Err(TokenError::RecoveryDelayNotPassed);
+    }
+
+    storage::remove_recoveries(env);
+    admin::replace_admin(env, new_admin);
+
+    Ok(())
+}
+
+fn require_guardian(env: &Env, guardian: &Address) -> Result<(), TokenError> {
+    guardian.require_auth();
+
+    let config = storage::get_guardian_config(env).ok_or(TokenError::NoGuardians)?;
+    if !config.guardians.contains(guardian) {
+        return Err(TokenError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+fn record_approval(
+    env: &Env,
+    recoveries: &mut Map<Address, Recovery>,
+    guardian: &Address,
+    new_admin: &Address,
+) -> Result<Recovery, TokenError> {
+    let config = storage::get_guardian_config(env).ok_or(TokenError::NoGuardians)?;
+
+    // A guardian backs at most one candidate at a time
+    remove_approval(env, recoveries, guardian)?;
+
+    let mut recovery = recoveries.get(new_admin.clone()).unwrap();
+    recovery.approvals.push_back(guardian.clone());
+    if recovery.ready_at.is_none() && recovery.approvals.len() >= config.threshold {
+        // The cancellation window starts once the guardians have agreed
+        recovery.ready_at = Some(env.ledger().sequence().saturating_add(config.delay_ledgers));
+    }
+
+    recoveries.set(new_admin.clone(), recovery.clone());
+    storage::set_recoveries(env, recoveries);
+
+    Ok(recovery)
+}
+
+/// Drop `guardian`'s approval wherever it is. Returns whether one was found.
+fn remove_approval(
+    env: &Env,
+    recoveries: &mut Map<Address, Recovery>,
+    guardian: &Address,
+) -> Result<bool, TokenError> {
+    let config = storage::get_guardian_config(env).ok_or(TokenError::NoGuardians)?;
+
+    for (new_admin, mut recovery) in recoveries.iter() {
+        let Some(index) = recovery.approvals.first_index_of(guardian) else {
+            continue;
+        };
+        recovery.approvals.remove(index);
+
+        if recovery.approvals.is_empty() {
+            recoveries.remove(new_admin);
+        } else {
+            if recovery.approvals.len() < config.threshold {
+                // Falling below the threshold restarts the delay once it is reached again
+                recovery.ready_at = None;
+            }
+            recoveries.set(new_admin, recovery);
+        }
+        return Ok(true);
+    }
+
+    Ok(false)
+}
@@ -1,14 +1,186 @@
-use soroban_sdk::{Env, Address, Symbol};
+use soroban_sdk::{symbol_short, Env, Address, Map, Symbol};
+use crate::fungible::{AllowanceValue, Metadata};
+use crate::admin::{Role, Succession};
+use crate::emission::EmissionSchedule;
+use crate::mint_limit::{MintLimit, MintWindow};
+use crate::recovery::{GuardianConfig, Recovery};
 
-const ADMIN_KEY: Symbol = Symbol::short("ADMIN");
+const ADMIN_KEY: Symbol = symbol_short!("ADMIN");
+const BALANCE_KEY: Symbol = symbol_short!("BALANCE");
+const ALLOWANCE_KEY: Symbol = symbol_short!("ALLOWANCE");
+const METADATA_KEY: Symbol = symbol_short!("METADATA");
+const LAST_ACTIVE_KEY: Symbol = symbol_short!("LASTACT");
+const SUCCESSION_KEY: Symbol = symbol_short!("SUCCESSN");
+const GUARDIANS_KEY: Symbol = symbol_short!("GUARDIANS");
+const RECOVERY_KEY: Symbol = symbol_short!("RECOVERY");
+const MINT_LIMIT_KEY: Symbol = symbol_short!("MINTLIM");
+const MINT_WINDOW_KEY: Symbol = symbol_short!("MINTWIN");
+const EMISSION_KEY: Symbol = symbol_short!("EMISSION");
+const ROLE_KEY: Symbol = symbol_short!("ROLE");
+const PENDING_ADMIN_KEY: Symbol = symbol_short!("PENDADMIN");
+const VERSION_KEY: Symbol = symbol_short!("ver");
+
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().instance().has(&ADMIN_KEY)
+}
 
 pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&ADMIN_KEY, admin);
 }
 
-pub fn get_admin(env: &Env) -> Address {
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ADMIN_KEY)
+}
+
+pub fn get_version(env: &Env) -> u32 {
+    env.storage().instance().get(&VERSION_KEY).unwrap_or(0)
+}
+
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&VERSION_KEY, &version);
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&PENDING_ADMIN_KEY)
+}
+
+pub fn set_pending_admin(env: &Env, pending: &Address) {
+    env.storage().instance().set(&PENDING_ADMIN_KEY, pending);
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&PENDING_ADMIN_KEY);
+}
+
+pub fn get_last_active(env: &Env) -> u32 {
+    env.storage().instance().get(&LAST_ACTIVE_KEY).unwrap_or(0)
+}
+
+pub fn set_last_active(env: &Env, ledger: u32) {
+    env.storage().instance().set(&LAST_ACTIVE_KEY, &ledger);
+}
+
+pub fn get_succession(env: &Env) -> Option<Succession> {
+    env.storage().instance().get(&SUCCESSION_KEY)
+}
+
+pub fn set_succession(env: &Env, succession: &Succession) {
+    env.storage().instance().set(&SUCCESSION_KEY, succession);
+}
+
+pub fn remove_succession(env: &Env) {
+    env.storage().instance().remove(&SUCCESSION_KEY);
+}
+
+pub fn get_guardian_config(env: &Env) -> Option<GuardianConfig> {
+    env.storage().instance().get(&GUARDIANS_KEY)
+}
+
+pub fn set_guardian_config(env: &Env, config: &GuardianConfig) {
+    env.storage().instance().set(&GUARDIANS_KEY, config);
+}
+
+pub fn get_recoveries(env: &Env) -> Map<Address, Recovery> {
     env.storage()
         .instance()
-        .get(&ADMIN_KEY)
-        .expect("Admin not set")
+        .get(&RECOVERY_KEY)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_recoveries(env: &Env, recoveries: &Map<Address, Recovery>) {
+    if recoveries.is_empty() {
+        remove_recoveries(env);
+    } else {
+        env.storage().instance().set(&RECOVERY_KEY, recoveries);
+    }
+}
+
+pub fn remove_recoveries(env: &Env) {
+    env.storage().instance().remove(&RECOVERY_KEY);
+}
+
+pub fn get_mint_limit(env: &Env) -> Option<MintLimit> {
+    env.storage().instance().get(&MINT_LIMIT_KEY)
+}
+
+pub fn set_mint_limit(env: &Env, limit: &MintLimit) {
+    env.storage().instance().set(&MINT_LIMIT_KEY, limit);
+}
+
+pub fn remove_mint_limit(env: &Env) {
+    env.storage().instance().remove(&MINT_LIMIT_KEY);
+}
+
+pub fn get_mint_window(env: &Env) -> Option<MintWindow> {
+    env.storage().instance().get(&MINT_WINDOW_KEY)
+}
+
+pub fn set_mint_window(env: &Env, window: &MintWindow) {
+    env.storage().instance().set(&MINT_WINDOW_KEY, window);
+}
+
+pub fn get_emission(env: &Env) -> Option<EmissionSchedule> {
+    env.storage().instance().get(&EMISSION_KEY)
+}
+
+pub fn set_emission(env: &Env, schedule: &EmissionSchedule) {
+    env.storage().instance().set(&EMISSION_KEY, schedule);
+}
+
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(ROLE_KEY, role, account.clone()))
+}
+
+pub fn set_role(env: &Env, role: Role, account: &Address) {
+    env.storage()
+        .persistent()
+        .set(&(ROLE_KEY, role, account.clone()), &true);
+}
+
+pub fn remove_role(env: &Env, role: Role, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&(ROLE_KEY, role, account.clone()));
+}
+
+pub fn balance_of(env: &Env, owner: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(BALANCE_KEY, owner.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_balance(env: &Env, owner: &Address, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&(BALANCE_KEY, owner.clone()), amount);
+}
+
+pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> Option<AllowanceValue> {
+    env.storage()
+        .temporary()
+        .get(&(ALLOWANCE_KEY, from.clone(), spender.clone()))
+}
+
+pub fn set_allowance(env: &Env, from: &Address, spender: &Address, allowance: &AllowanceValue) {
+    let key = (ALLOWANCE_KEY, from.clone(), spender.clone());
+    env.storage().temporary().set(&key, allowance);
+
+    // Keep the entry alive until it expires
+    if allowance.amount > 0 {
+        let live_for = allowance
+            .expiration_ledger
+            .saturating_sub(env.ledger().sequence());
+        env.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
+
+pub fn get_metadata(env: &Env) -> Option<Metadata> {
+    env.storage().instance().get(&METADATA_KEY)
+}
+
+pub fn set_metadata(env: &Env, metadata: &Metadata) {
+    env.storage().instance().set(&METADATA_KEY, metadata);
 }
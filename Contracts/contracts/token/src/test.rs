@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+
+// SEP-41 entry points trap on failure, so their error paths are checked on the helpers
+
+#[test]
+fn test_transfer_beyond_balance_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, TokenContract);
+    let holder = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        fungible::receive_balance(&env, &holder, 1_000).unwrap();
+
+        let result = fungible::move_balance(&env, &holder, &to, 1_001);
+        assert_eq!(result, Err(TokenError::InsufficientBalance));
+        assert_eq!(storage::balance_of(&env, &holder), 1_000);
+    });
+}
+
+#[test]
+fn test_spend_beyond_allowance_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, TokenContract);
+    let holder = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        fungible::approve(&env, &holder, &spender, 100, 200).unwrap();
+
+        let result = fungible::spend_allowance(&env, &holder, &spender, 101);
+        assert_eq!(result, Err(TokenError::InsufficientAllowance));
+        assert_eq!(fungible::read_allowance(&env, &holder, &spender).amount, 100);
+    });
+}
+
+#[test]
+fn test_negative_amount_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, TokenContract);
+    let holder = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let result = fungible::move_balance(&env, &holder, &holder, -1);
+        assert_eq!(result, Err(TokenError::InvalidAmount));
+    });
+}
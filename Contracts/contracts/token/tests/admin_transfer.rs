@@ -0,0 +1,52 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+use token::TokenError;
+
+mod common;
+
+#[test]
+fn admin_changes_only_after_acceptance() {
+    let env = Env::default();
+    let (client, admin) = common::setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.transfer_admin(&new_admin);
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(client.get_admin(), Some(new_admin));
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn cancelled_transfer_cannot_be_accepted() {
+    let env = Env::default();
+    let (client, admin) = common::setup(&env);
+
+    client.transfer_admin(&Address::generate(&env));
+    client.cancel_admin_transfer();
+
+    let result = client.try_accept_admin();
+    assert_eq!(result, Err(Ok(TokenError::NoPendingAdmin)));
+    assert_eq!(client.get_admin(), Some(admin));
+}
+
+#[test]
+fn accepted_admin_does_not_inherit_successor() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+    let heir = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    client.set_successor(&heir, &10);
+    client.transfer_admin(&new_admin);
+    client.accept_admin();
+
+    assert_eq!(client.get_successor(), None);
+
+    // The new admin stays silent past the old heartbeat
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    assert_eq!(client.try_claim_admin(&heir), Err(Ok(TokenError::NoSuccessor)));
+    assert_eq!(client.get_admin(), Some(new_admin));
+}
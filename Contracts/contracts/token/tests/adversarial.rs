@@ -1,14 +1,15 @@
+use soroban_sdk::Env;
+use token::TokenError;
+
+mod common;
+
 #[test]
-#[should_panic(expected = "Overflow")]
 fn mint_overflow_attack() {
     let env = Env::default();
-    let admin = Address::random(&env);
+    let (client, admin) = common::setup(&env);
 
-    TokenContract::initialize(env.clone(), admin.clone());
+    client.mint(&admin, &admin, &i128::MAX);
 
-    TokenContract::mint(
-        env,
-        admin,
-        i128::MAX
-    );
+    let result = client.try_mint(&admin, &admin, &1);
+    assert_eq!(result, Err(Ok(TokenError::Overflow)));
 }
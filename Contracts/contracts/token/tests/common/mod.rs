@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+use token::{TokenContract, TokenContractClient};
+
+pub const DECIMALS: u32 = 7;
+pub const NAME: &str = "Stellara";
+pub const SYMBOL: &str = "STLR";
+
+/// Register a token contract without initializing it
+pub fn register(env: &Env) -> TokenContractClient<'_> {
+    let contract_id = env.register_contract(None, TokenContract);
+    TokenContractClient::new(env, &contract_id)
+}
+
+/// Initialize `client` with `admin` and the standard test metadata
+pub fn initialize(client: &TokenContractClient, admin: &Address) {
+    client.initialize(
+        admin,
+        &DECIMALS,
+        &String::from_str(&client.env, NAME),
+        &String::from_str(&client.env, SYMBOL),
+    );
+}
+
+/// Register and initialize a token with every auth mocked; returns it and its admin
+pub fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+
+    let client = register(env);
+    let admin = Address::generate(env);
+    initialize(&client, &admin);
+
+    (client, admin)
+}
@@ -0,0 +1,113 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as Sep41Client;
+use soroban_sdk::{vec, Address, Env};
+use token::{Allocation, TokenContractClient, TokenError};
+
+mod common;
+
+const EPOCH: u32 = 100;
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address, Address) {
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+
+    let (client, _admin) = common::setup(env);
+
+    let treasury = Address::generate(env);
+    let rewards = Address::generate(env);
+    let epochs = vec![
+        env,
+        vec![
+            env,
+            Allocation { to: treasury.clone(), amount: 500 },
+            Allocation { to: rewards.clone(), amount: 300 },
+        ],
+        vec![env, Allocation { to: rewards.clone(), amount: 200 }],
+    ];
+    client.set_emission_schedule(&EPOCH, &epochs);
+
+    (client, treasury, rewards)
+}
+
+#[test]
+fn epochs_advance_when_due() {
+    let env = Env::default();
+    let (client, treasury, rewards) = setup(&env);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000 + EPOCH);
+    assert_eq!(client.advance_epoch(), 0);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000 + 2 * EPOCH);
+    assert_eq!(client.advance_epoch(), 1);
+
+    let schedule = client.get_emission_schedule().unwrap();
+    assert_eq!(schedule.next_epoch, 2);
+
+    let sep41 = Sep41Client::new(&env, &client.address);
+    assert_eq!(sep41.balance(&treasury), 500);
+    assert_eq!(sep41.balance(&rewards), 500);
+}
+
+#[test]
+fn emission_ignores_mint_limit() {
+    let env = Env::default();
+    let (client, treasury, _rewards) = setup(&env);
+
+    // A limit that a single allocation would exceed
+    client.set_mint_limit(&1, &EPOCH);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000 + EPOCH);
+    client.advance_epoch();
+
+    assert_eq!(Sep41Client::new(&env, &client.address).balance(&treasury), 500);
+    assert_eq!(client.minted_in_window(), 0);
+}
+
+#[test]
+fn epoch_cannot_advance_early() {
+    let env = Env::default();
+    let (client, _treasury, _rewards) = setup(&env);
+
+    let result = client.try_advance_epoch();
+    assert_eq!(result, Err(Ok(TokenError::EpochNotDue)));
+}
+
+#[test]
+fn schedule_ends_after_last_epoch() {
+    let env = Env::default();
+    let (client, _treasury, _rewards) = setup(&env);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000 + 5 * EPOCH);
+    client.advance_epoch();
+    client.advance_epoch();
+    let result = client.try_advance_epoch();
+    assert_eq!(result, Err(Ok(TokenError::EmissionFinished)));
+}
+
+#[test]
+fn schedule_is_immutable() {
+    let env = Env::default();
+    let (client, treasury, _rewards) = setup(&env);
+
+    let epochs = vec![&env, vec![&env, Allocation { to: treasury, amount: 1 }]];
+    let result = client.try_set_emission_schedule(&EPOCH, &epochs);
+    assert_eq!(result, Err(Ok(TokenError::EmissionAlreadySet)));
+}
+
+#[test]
+fn empty_schedule_rejected() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+
+    let result = client.try_set_emission_schedule(&EPOCH, &vec![&env]);
+    assert_eq!(result, Err(Ok(TokenError::EmptyEmission)));
+
+    let result = client.try_set_emission_schedule(&EPOCH, &vec![&env, vec![&env]]);
+    assert_eq!(result, Err(Ok(TokenError::EmptyEmission)));
+
+    // Nothing was stored, so a real schedule can still be set
+    let epochs = vec![
+        &env,
+        vec![&env, Allocation { to: Address::generate(&env), amount: 1 }],
+    ];
+    client.set_emission_schedule(&EPOCH, &epochs);
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as Sep41Client;
+use soroban_sdk::{Address, Env, String};
+use token::{TokenContractClient, TokenError};
+
+mod common;
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Sep41Client<'_>, Address) {
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (client, admin) = common::setup(env);
+
+    let holder = Address::generate(env);
+    client.mint(&admin, &holder, &1_000);
+
+    let sep41 = Sep41Client::new(env, &client.address);
+    (client, sep41, holder)
+}
+
+#[test]
+fn exposes_sep41_metadata() {
+    let env = Env::default();
+    let (_client, sep41, holder) = setup(&env);
+
+    assert_eq!(sep41.decimals(), 7);
+    assert_eq!(sep41.name(), String::from_str(&env, "Stellara"));
+    assert_eq!(sep41.symbol(), String::from_str(&env, "STLR"));
+    assert_eq!(sep41.balance(&holder), 1_000);
+}
+
+#[test]
+fn transfer_and_burn_move_balances() {
+    let env = Env::default();
+    let (_client, sep41, holder) = setup(&env);
+    let to = Address::generate(&env);
+
+    sep41.transfer(&holder, &to, &400);
+    sep41.burn(&to, &100);
+
+    assert_eq!(sep41.balance(&holder), 600);
+    assert_eq!(sep41.balance(&to), 300);
+}
+
+#[test]
+fn transfer_from_spends_allowance() {
+    let env = Env::default();
+    let (_client, sep41, holder) = setup(&env);
+    let spender = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    sep41.approve(&holder, &spender, &500, &200);
+    sep41.transfer_from(&spender, &holder, &to, &300);
+    sep41.burn_from(&spender, &holder, &100);
+
+    assert_eq!(sep41.allowance(&holder, &spender), 100);
+    assert_eq!(sep41.balance(&holder), 600);
+    assert_eq!(sep41.balance(&to), 300);
+}
+
+#[test]
+fn expired_allowance_reads_as_zero() {
+    let env = Env::default();
+    let (_client, sep41, holder) = setup(&env);
+    let spender = Address::generate(&env);
+
+    sep41.approve(&holder, &spender, &500, &150);
+    env.ledger().with_mut(|li| li.sequence_number = 151);
+
+    assert_eq!(sep41.allowance(&holder, &spender), 0);
+}
+
+#[test]
+fn preview_reports_why_a_transfer_would_fail() {
+    let env = Env::default();
+    let (client, sep41, holder) = setup(&env);
+    let spender = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    assert_eq!(client.try_preview_transfer(&None, &holder, &to, &1_000), Ok(Ok(())));
+
+    let result = client.try_preview_transfer(&None, &holder, &to, &-1);
+    assert_eq!(result, Err(Ok(TokenError::InvalidAmount)));
+
+    let result = client.try_preview_transfer(&None, &holder, &to, &1_001);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientBalance)));
+
+    // transfer_from is checked against the spender's allowance first
+    sep41.approve(&holder, &spender, &500, &200);
+    let via = Some(spender.clone());
+    assert_eq!(client.try_preview_transfer(&via, &holder, &to, &500), Ok(Ok(())));
+
+    let result = client.try_preview_transfer(&via, &holder, &to, &501);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientAllowance)));
+
+    // Nothing moved
+    assert_eq!(sep41.balance(&holder), 1_000);
+    assert_eq!(sep41.allowance(&holder, &spender), 500);
+}
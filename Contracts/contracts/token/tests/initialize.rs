@@ -0,0 +1,68 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+use token::TokenError;
+
+mod common;
+
+#[test]
+fn initialize_only_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = common::register(&env);
+
+    common::initialize(&client, &Address::generate(&env));
+
+    let result = client.try_initialize(
+        &Address::generate(&env),
+        &7,
+        &String::from_str(&env, "Stellara"),
+        &String::from_str(&env, "STLR"),
+    );
+    assert_eq!(result, Err(Ok(TokenError::AlreadyInitialized)));
+}
+
+#[test]
+fn contract_cannot_be_its_own_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = common::register(&env);
+
+    let result = client.try_initialize(
+        &client.address,
+        &7,
+        &String::from_str(&env, "Stellara"),
+        &String::from_str(&env, "STLR"),
+    );
+    assert_eq!(result, Err(Ok(TokenError::InvalidAdmin)));
+}
+
+#[test]
+fn rejects_empty_or_oversized_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = common::register(&env);
+    let admin = Address::generate(&env);
+
+    let result = client.try_initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, ""),
+        &String::from_str(&env, "STLR"),
+    );
+    assert_eq!(result, Err(Ok(TokenError::InvalidMetadata)));
+
+    let result = client.try_initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Stellara"),
+        &String::from_str(&env, "STELLARATOKEN"),
+    );
+    assert_eq!(result, Err(Ok(TokenError::InvalidMetadata)));
+
+    // Nothing was stored, so a valid initialize still goes through
+    common::initialize(&client, &admin);
+    assert_eq!(client.get_admin(), Some(admin));
+}
@@ -0,0 +1,54 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+use token::{Role, TokenContractClient, TokenError};
+
+mod common;
+
+const WINDOW: u32 = 10;
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (client, _admin) = common::setup(env);
+    client.set_mint_limit(&1_000, &WINDOW);
+
+    let minter = Address::generate(env);
+    client.grant_role(&Role::Minter, &minter);
+
+    (client, minter)
+}
+
+#[test]
+fn mints_within_limit_and_window_resets() {
+    let env = Env::default();
+    let (client, minter) = setup(&env);
+    let to = Address::generate(&env);
+
+    client.mint(&minter, &to, &600);
+    client.mint(&minter, &to, &400);
+    assert_eq!(client.minted_in_window(), 1_000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100 + WINDOW);
+    assert_eq!(client.minted_in_window(), 0);
+    client.mint(&minter, &to, &1_000);
+}
+
+#[test]
+fn mint_over_limit_rejected() {
+    let env = Env::default();
+    let (client, minter) = setup(&env);
+    let to = Address::generate(&env);
+
+    client.mint(&minter, &to, &600);
+    let result = client.try_mint(&minter, &to, &401);
+    assert_eq!(result, Err(Ok(TokenError::MintLimitExceeded)));
+}
+
+#[test]
+fn removing_limit_lifts_cap() {
+    let env = Env::default();
+    let (client, minter) = setup(&env);
+
+    client.remove_mint_limit();
+    client.mint(&minter, &Address::generate(&env), &5_000);
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+use token::{AdminOp, TokenError};
+
+mod common;
+
+#[test]
+fn multicall_applies_every_op() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+    let successor = Address::generate(&env);
+    let guardian = Address::generate(&env);
+
+    client.admin_multicall(&vec![
+        &env,
+        AdminOp::SetMintLimit(1_000, 10),
+        AdminOp::SetSuccessor(successor.clone(), 100),
+        AdminOp::SetGuardians(vec![&env, guardian], 1, 50),
+    ]);
+
+    assert_eq!(client.get_mint_limit().unwrap().max_per_window, 1_000);
+    assert_eq!(client.get_successor().unwrap().successor, successor);
+    assert_eq!(client.get_guardians().unwrap().threshold, 1);
+}
+
+#[test]
+fn failing_op_reverts_the_batch() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+
+    let result = client.try_admin_multicall(&vec![
+        &env,
+        AdminOp::SetMintLimit(1_000, 10),
+        AdminOp::SetSuccessor(Address::generate(&env), 0),
+    ]);
+
+    assert_eq!(result, Err(Ok(TokenError::InvalidHeartbeat)));
+    assert_eq!(client.get_mint_limit(), None);
+}
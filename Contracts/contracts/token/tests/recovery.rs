@@ -0,0 +1,184 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env};
+use token::{TokenContractClient, TokenError};
+
+mod common;
+
+const DELAY: u32 = 500;
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address, Address, Address, Address) {
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (client, admin) = common::setup(env);
+
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    let carol = Address::generate(env);
+    client.set_guardians(&vec![env, alice.clone(), bob.clone(), carol.clone()], &2, &DELAY);
+
+    (client, admin, alice, bob, carol)
+}
+
+#[test]
+fn guardians_rotate_admin_after_delay() {
+    let env = Env::default();
+    let (client, _admin, alice, bob, _carol) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_recovery(&alice, &new_admin);
+    assert_eq!(client.get_recovery(&new_admin).unwrap().ready_at, None);
+
+    client.approve_recovery(&bob, &new_admin);
+    assert_eq!(client.get_recovery(&new_admin).unwrap().ready_at, Some(100 + DELAY));
+
+    env.ledger().with_mut(|li| li.sequence_number = 100 + DELAY);
+    client.execute_recovery(&new_admin);
+
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_recovery(&new_admin), None);
+}
+
+#[test]
+fn recovery_waits_for_delay() {
+    let env = Env::default();
+    let (client, _admin, alice, bob, _carol) = setup(&env);
+
+    let new_admin = Address::generate(&env);
+    client.propose_recovery(&alice, &new_admin);
+    client.approve_recovery(&bob, &new_admin);
+    let result = client.try_execute_recovery(&new_admin);
+    assert_eq!(result, Err(Ok(TokenError::RecoveryDelayNotPassed)));
+}
+
+#[test]
+fn recovery_needs_threshold() {
+    let env = Env::default();
+    let (client, _admin, alice, _bob, _carol) = setup(&env);
+
+    let new_admin = Address::generate(&env);
+    client.propose_recovery(&alice, &new_admin);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100 + DELAY);
+    let result = client.try_execute_recovery(&new_admin);
+    assert_eq!(result, Err(Ok(TokenError::NotEnoughApprovals)));
+}
+
+#[test]
+fn admin_can_cancel_during_window() {
+    let env = Env::default();
+    let (client, _admin, alice, bob, _carol) = setup(&env);
+
+    let new_admin = Address::generate(&env);
+    client.propose_recovery(&alice, &new_admin);
+    client.approve_recovery(&bob, &new_admin);
+    client.cancel_recovery();
+
+    env.ledger().with_mut(|li| li.sequence_number = 100 + DELAY);
+    let result = client.try_execute_recovery(&new_admin);
+    assert_eq!(result, Err(Ok(TokenError::NoRecoveryPending)));
+}
+
+#[test]
+fn non_guardian_cannot_propose() {
+    let env = Env::default();
+    let (client, _admin, _alice, _bob, _carol) = setup(&env);
+
+    let result = client.try_propose_recovery(&Address::generate(&env), &Address::generate(&env));
+    assert_eq!(result, Err(Ok(TokenError::Unauthorized)));
+}
+
+#[test]
+fn guardian_cannot_approve_twice() {
+    let env = Env::default();
+    let (client, _admin, alice, _bob, _carol) = setup(&env);
+
+    let new_admin = Address::generate(&env);
+    client.propose_recovery(&alice, &new_admin);
+    let result = client.try_approve_recovery(&alice, &new_admin);
+    assert_eq!(result, Err(Ok(TokenError::AlreadyApproved)));
+}
+
+#[test]
+fn recovery_drops_the_old_admins_successor() {
+    let env = Env::default();
+    let (client, _admin, alice, bob, _carol) = setup(&env);
+    let attacker = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    // A compromised admin key plants its own successor before being rotated out
+    client.set_successor(&attacker, &1);
+
+    client.propose_recovery(&alice, &new_admin);
+    client.approve_recovery(&bob, &new_admin);
+    env.ledger().with_mut(|li| li.sequence_number = 100 + DELAY);
+    client.execute_recovery(&new_admin);
+
+    assert_eq!(client.get_successor(), None);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100 + DELAY + 2);
+    assert_eq!(client.try_claim_admin(&attacker), Err(Ok(TokenError::NoSuccessor)));
+    assert_eq!(client.get_admin(), Some(new_admin));
+}
+
+#[test]
+fn bogus_proposal_does_not_block_recovery() {
+    let env = Env::default();
+    let (client, _admin, alice, bob, carol) = setup(&env);
+    let bogus = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    // A rogue guardian proposes their own candidate first
+    client.propose_recovery(&carol, &bogus);
+
+    // Approvals are bound to a candidate, so the honest guardians proceed
+    client.propose_recovery(&alice, &new_admin);
+    client.approve_recovery(&bob, &new_admin);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100 + DELAY);
+    assert_eq!(
+        client.try_execute_recovery(&bogus),
+        Err(Ok(TokenError::NotEnoughApprovals))
+    );
+    client.execute_recovery(&new_admin);
+    assert_eq!(client.get_admin(), Some(new_admin));
+    assert_eq!(client.get_recoveries().len(), 0);
+}
+
+#[test]
+fn guardian_withdraws_mistyped_proposal() {
+    let env = Env::default();
+    let (client, _admin, alice, bob, _carol) = setup(&env);
+    let typo = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_recovery(&alice, &typo);
+    client.withdraw_recovery(&alice);
+    assert_eq!(client.get_recovery(&typo), None);
+    assert_eq!(
+        client.try_withdraw_recovery(&alice),
+        Err(Ok(TokenError::NoRecoveryPending))
+    );
+
+    // Moving an approval takes it off the previous candidate
+    client.propose_recovery(&alice, &typo);
+    client.propose_recovery(&bob, &new_admin);
+    client.approve_recovery(&alice, &new_admin);
+    assert_eq!(client.get_recovery(&typo), None);
+    assert_eq!(
+        client.get_recovery(&new_admin).unwrap().ready_at,
+        Some(100 + DELAY)
+    );
+
+    // Dropping below the threshold clears the countdown
+    client.withdraw_recovery(&bob);
+    assert_eq!(client.get_recovery(&new_admin).unwrap().ready_at, None);
+}
+
+#[test]
+fn duplicate_guardians_rejected() {
+    let env = Env::default();
+    let (client, _admin, alice, bob, _carol) = setup(&env);
+
+    let result = client.try_set_guardians(&vec![&env, alice.clone(), alice, bob], &3, &DELAY);
+    assert_eq!(result, Err(Ok(TokenError::DuplicateGuardian)));
+}
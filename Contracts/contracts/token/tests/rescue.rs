@@ -0,0 +1,150 @@
+use soroban_sdk::token::{Client as AssetClient, StellarAssetClient};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, Symbol};
+use token::TokenError;
+
+mod common;
+
+// Minimal NFT collection exposing the `transfer(from, to, token_id)` rescue relies on
+#[contract]
+pub struct StubNft;
+
+#[contractimpl]
+impl StubNft {
+    pub fn mint(env: Env, to: Address, token_id: u64) {
+        env.storage().persistent().set(&token_id, &to);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, token_id: u64) {
+        from.require_auth();
+        let owner: Address = env.storage().persistent().get(&token_id).unwrap();
+        assert_eq!(owner, from);
+        env.storage().persistent().set(&token_id, &to);
+    }
+
+    pub fn owner_of(env: Env, token_id: u64) -> Address {
+        env.storage().persistent().get(&token_id).unwrap()
+    }
+}
+
+#[test]
+fn admin_rescues_foreign_token() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+
+    // A SEP-41 asset mistakenly sent to the token contract
+    let issuer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(issuer);
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &500);
+
+    let user = Address::generate(&env);
+    client.rescue_token(&asset, &500, &user);
+
+    let asset_client = AssetClient::new(&env, &asset);
+    assert_eq!(asset_client.balance(&user), 500);
+    assert_eq!(asset_client.balance(&client.address), 0);
+}
+
+#[test]
+fn admin_rescues_foreign_nft() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+
+    // An NFT mistakenly sent to the token contract
+    let nft = env.register_contract(None, StubNft);
+    let nft_client = StubNftClient::new(&env, &nft);
+    nft_client.mint(&client.address, &42);
+
+    let user = Address::generate(&env);
+    client.rescue_nft(&nft, &42, &user);
+
+    assert_eq!(nft_client.owner_of(&42), user);
+    let rescued = (
+        client.address.clone(),
+        (Symbol::new(&env, "rescue_nft"), nft).into_val(&env),
+        (user, 42_u64).into_val(&env),
+    );
+    assert!(env.events().all().contains(&rescued));
+}
+
+#[test]
+fn cannot_rescue_own_nft() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+
+    let user = Address::generate(&env);
+    let result = client.try_rescue_nft(&client.address, &1, &user);
+    assert_eq!(result, Err(Ok(TokenError::CannotRescueOwnToken)));
+}
+
+#[test]
+fn cannot_rescue_own_token() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+
+    let user = Address::generate(&env);
+    let result = client.try_rescue_token(&client.address, &1, &user);
+    assert_eq!(result, Err(Ok(TokenError::CannotRescueOwnToken)));
+}
+
+#[test]
+fn rescue_requires_admin_auth() {
+    let env = Env::default();
+    let (client, admin) = common::setup(&env);
+
+    let issuer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(issuer);
+    StellarAssetClient::new(&env, &asset).mint(&client.address, &500);
+
+    let user = Address::generate(&env);
+    client.rescue_token(&asset, &500, &user);
+
+    // Only the stored admin's signature authorized the rescue
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin,
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "rescue_token"),
+                    (asset, 500_i128, user).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+fn admin_recovers_own_tokens_held_by_contract() {
+    let env = Env::default();
+    let (client, admin) = common::setup(&env);
+
+    // Units minted straight to the contract address by mistake
+    client.mint(&admin, &client.address, &300);
+
+    let user = Address::generate(&env);
+    client.recover_own_tokens(&120, &user);
+
+    // The remaining 180 is still held by the contract
+    client.recover_own_tokens(&180, &user);
+
+    // Indexers see a standard SEP-41 transfer out of the contract
+    let transfer = (
+        client.address.clone(),
+        (Symbol::new(&env, "transfer"), client.address.clone(), user.clone()).into_val(&env),
+        180_i128.into_val(&env),
+    );
+    assert!(env.events().all().contains(&transfer));
+}
+
+#[test]
+fn cannot_recover_more_than_contract_holds() {
+    let env = Env::default();
+    let (client, admin) = common::setup(&env);
+
+    client.mint(&admin, &client.address, &100);
+    let result = client.try_recover_own_tokens(&101, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(TokenError::InsufficientBalance)));
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::Client as Sep41Client;
+use soroban_sdk::{Address, Env};
+use token::{Role, TokenError};
+
+mod common;
+
+#[test]
+fn granted_minter_can_mint() {
+    let env = Env::default();
+    let (client, admin) = common::setup(&env);
+    let minter = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    assert!(client.has_role(&Role::Minter, &admin));
+    assert!(!client.has_role(&Role::Minter, &minter));
+
+    client.grant_role(&Role::Minter, &minter);
+    client.mint(&minter, &to, &250);
+
+    assert_eq!(Sep41Client::new(&env, &client.address).balance(&to), 250);
+}
+
+#[test]
+fn account_without_role_cannot_mint() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_mint(&outsider, &outsider, &1);
+    assert_eq!(result, Err(Ok(TokenError::MissingRole)));
+}
+
+#[test]
+fn revoked_minter_loses_access() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+    let minter = Address::generate(&env);
+
+    client.grant_role(&Role::Minter, &minter);
+    client.revoke_role(&Role::Minter, &minter);
+
+    let result = client.try_mint(&minter, &minter, &1);
+    assert_eq!(result, Err(Ok(TokenError::MissingRole)));
+}
@@ -0,0 +1,80 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+use token::{Succession, TokenContractClient, TokenError};
+
+mod common;
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (client, admin) = common::setup(env);
+
+    (client, admin)
+}
+
+#[test]
+fn successor_claims_after_inactivity() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let heir = Address::generate(&env);
+
+    client.set_successor(&heir, &1_000);
+    assert_eq!(
+        client.get_successor(),
+        Some(Succession {
+            successor: heir.clone(),
+            heartbeat_ledgers: 1_000,
+        })
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_101);
+    client.claim_admin(&heir);
+
+    // The heir is now the admin and the switch is disarmed
+    assert_eq!(client.get_admin(), Some(heir));
+    assert_eq!(client.get_successor(), None);
+    assert_eq!(client.last_admin_activity(), 1_101);
+}
+
+#[test]
+fn heartbeat_resets_the_clock() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let heir = Address::generate(&env);
+
+    client.set_successor(&heir, &1_000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    client.heartbeat();
+    assert_eq!(client.last_admin_activity(), 1_000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_101);
+    let result = client.try_claim_admin(&heir);
+    assert_eq!(result, Err(Ok(TokenError::AdminStillActive)));
+}
+
+#[test]
+fn only_designated_successor_can_claim() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.set_successor(&Address::generate(&env), &10);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    let result = client.try_claim_admin(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(TokenError::Unauthorized)));
+}
+
+#[test]
+fn cleared_successor_cannot_claim() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let heir = Address::generate(&env);
+
+    client.set_successor(&heir, &10);
+    client.clear_successor();
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    let result = client.try_claim_admin(&heir);
+    assert_eq!(result, Err(Ok(TokenError::NoSuccessor)));
+}
@@ -0,0 +1,51 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
+use token::{Role, TokenError};
+
+mod common;
+
+// Release build of this crate, kept next to the token factory's tests
+const TOKEN_WASM: &[u8] = include_bytes!("../../token-factory/testdata/token.wasm");
+
+#[test]
+fn fresh_deployment_is_current() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+
+    assert_eq!(client.get_version(), 1);
+    assert_eq!(client.try_migrate(), Err(Ok(TokenError::AlreadyMigrated)));
+}
+
+#[test]
+fn upgrade_requires_upgrader_role() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_upgrade(&outsider, &BytesN::from_array(&env, &[0; 32]));
+    assert_eq!(result, Err(Ok(TokenError::MissingRole)));
+}
+
+#[test]
+fn upgrader_installs_new_wasm_and_migrates() {
+    let env = Env::default();
+    let (client, _admin) = common::setup(&env);
+    let upgrader = Address::generate(&env);
+    client.grant_role(&Role::Upgrader, &upgrader);
+
+    // Running the real wasm exceeds the default test budget
+    env.budget().reset_unlimited();
+    let wasm_hash = env.deployer().upload_contract_wasm(TOKEN_WASM);
+    client.upgrade(&upgrader, &wasm_hash);
+
+    // Instance storage survives the code swap
+    assert_eq!(client.symbol(), String::from_str(&env, "STLR"));
+    assert!(client.has_role(&Role::Upgrader, &upgrader));
+
+    // Pretend the stored data predates the running schema
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&symbol_short!("ver"), &0_u32);
+    });
+    assert_eq!(client.migrate(), 1);
+    assert_eq!(client.get_version(), 1);
+}
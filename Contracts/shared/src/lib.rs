@@ -12,6 +12,8 @@ pub struct ContractConfig {
 
 pub mod fees;
 pub mod governance;
+pub mod merkle;
+pub mod nonces;
 
 /// Standard contract error codes
 pub mod errors {
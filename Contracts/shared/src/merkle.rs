@@ -0,0 +1,90 @@
+use soroban_sdk::{contracttype, Bytes, BytesN, Env, Vec};
+
+/// Hash function used to build a merkle tree
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum HashAlgorithm {
+    Sha256 = 0,
+    Keccak256 = 1,
+}
+
+/// Hashes leaf data into a tree leaf as `H(H(data))`.
+///
+/// Leaves are hashed twice so that no leaf can collide with an internal node,
+/// which is a single hash of 64 bytes. This matches OpenZeppelin's
+/// `StandardMerkleTree` when `data` is the ABI-encoded leaf.
+pub fn hash_leaf(env: &Env, algorithm: HashAlgorithm, data: &Bytes) -> BytesN<32> {
+    let hash = |preimage: &Bytes| match algorithm {
+        HashAlgorithm::Sha256 => env.crypto().sha256(preimage),
+        HashAlgorithm::Keccak256 => env.crypto().keccak256(preimage),
+    };
+
+    let inner = hash(data);
+    hash(&Bytes::from_array(env, &inner.to_array()))
+}
+
+/// Verifies a SHA-256 merkle proof built over sorted pairs.
+///
+/// `leaf` must come from [`hash_leaf`]. Never pass raw or caller-supplied
+/// 32-byte values as leaves: an intermediate node would then verify as a leaf
+/// (a second-preimage attack).
+///
+/// # Arguments
+/// * `env` - The environment
+/// * `root` - The committed merkle root
+/// * `leaf` - The leaf being proven, as returned by `hash_leaf`
+/// * `proof` - Sibling hashes from the leaf up to the root
+///
+/// # Returns
+/// * `bool` - True if `leaf` is included under `root`
+pub fn verify_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    verify_proof_with(env, HashAlgorithm::Sha256, root, leaf, proof)
+}
+
+/// Verifies a merkle proof built over sorted pairs with the given hash function.
+///
+/// Sorting each pair before hashing means proofs do not need to carry
+/// left/right position flags, matching the common OpenZeppelin layout.
+/// As with [`verify_proof`], `leaf` must come from [`hash_leaf`].
+pub fn verify_proof_with(
+    env: &Env,
+    algorithm: HashAlgorithm,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut computed = leaf.clone();
+    for sibling in proof.iter() {
+        computed = hash_pair(env, algorithm, &computed, &sibling);
+    }
+
+    &computed == root
+}
+
+/// Hashes two nodes in sorted order.
+pub fn hash_pair(
+    env: &Env,
+    algorithm: HashAlgorithm,
+    a: &BytesN<32>,
+    b: &BytesN<32>,
+) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    let mut preimage = Bytes::from_array(env, &first.to_array());
+    preimage.append(&Bytes::from_array(env, &second.to_array()));
+
+    match algorithm {
+        HashAlgorithm::Sha256 => env.crypto().sha256(&preimage),
+        HashAlgorithm::Keccak256 => env.crypto().keccak256(&preimage),
+    }
+}
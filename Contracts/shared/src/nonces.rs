@@ -0,0 +1,75 @@
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum NonceError {
+    InvalidNonce = 5001,
+    NullifierUsed = 5002,
+}
+
+/// Storage keys owned by the nonce registry
+#[contracttype]
+#[derive(Clone)]
+pub enum NonceKey {
+    Nonce(Address),
+    Nullifier(BytesN<32>),
+}
+
+/// Replay protection shared by every signature-based feature of a contract.
+///
+/// Sequential nonces suit per-signer messages (permits, meta-transactions);
+/// nullifiers suit one-shot vouchers whose hash is the identity.
+pub struct NonceRegistry;
+
+impl NonceRegistry {
+    /// Returns the nonce the next signed message from `owner` must carry.
+    pub fn next_nonce(env: &Env, owner: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&NonceKey::Nonce(owner.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Consumes `nonce` for `owner`, rejecting anything but the expected value.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `owner` - The signer the nonce belongs to
+    /// * `nonce` - The nonce carried by the signed message
+    ///
+    /// # Returns
+    /// * `Result<(), NonceError>` - Ok if the nonce was current, Error otherwise
+    pub fn consume_nonce(env: &Env, owner: &Address, nonce: u64) -> Result<(), NonceError> {
+        let expected = Self::next_nonce(env, owner);
+        if nonce != expected {
+            return Err(NonceError::InvalidNonce);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&NonceKey::Nonce(owner.clone()), &(expected + 1));
+
+        Ok(())
+    }
+
+    /// Returns true if `nullifier` has already been spent.
+    pub fn is_nullifier_used(env: &Env, nullifier: &BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&NonceKey::Nullifier(nullifier.clone()))
+    }
+
+    /// Marks `nullifier` as spent, rejecting a second use.
+    pub fn use_nullifier(env: &Env, nullifier: &BytesN<32>) -> Result<(), NonceError> {
+        if Self::is_nullifier_used(env, nullifier) {
+            return Err(NonceError::NullifierUsed);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&NonceKey::Nullifier(nullifier.clone()), &true);
+
+        Ok(())
+    }
+}
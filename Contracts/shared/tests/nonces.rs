@@ -0,0 +1,49 @@
+use shared::nonces::{NonceError, NonceRegistry};
+use soroban_sdk::{contract, testutils::Address as _, Address, BytesN, Env};
+
+#[contract]
+struct Host;
+
+#[test]
+fn nonces_are_sequential_per_owner() {
+    let env = Env::default();
+    let host = env.register_contract(None, Host);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        assert_eq!(NonceRegistry::next_nonce(&env, &alice), 0);
+        assert_eq!(NonceRegistry::consume_nonce(&env, &alice, 0), Ok(()));
+        assert_eq!(NonceRegistry::next_nonce(&env, &alice), 1);
+
+        // Replaying or skipping ahead is rejected
+        assert_eq!(
+            NonceRegistry::consume_nonce(&env, &alice, 0),
+            Err(NonceError::InvalidNonce)
+        );
+        assert_eq!(
+            NonceRegistry::consume_nonce(&env, &alice, 5),
+            Err(NonceError::InvalidNonce)
+        );
+
+        // Other owners are unaffected
+        assert_eq!(NonceRegistry::next_nonce(&env, &bob), 0);
+    });
+}
+
+#[test]
+fn nullifiers_can_only_be_used_once() {
+    let env = Env::default();
+    let host = env.register_contract(None, Host);
+    let voucher = BytesN::from_array(&env, &[9; 32]);
+
+    env.as_contract(&host, || {
+        assert!(!NonceRegistry::is_nullifier_used(&env, &voucher));
+        assert_eq!(NonceRegistry::use_nullifier(&env, &voucher), Ok(()));
+        assert!(NonceRegistry::is_nullifier_used(&env, &voucher));
+        assert_eq!(
+            NonceRegistry::use_nullifier(&env, &voucher),
+            Err(NonceError::NullifierUsed)
+        );
+    });
+}